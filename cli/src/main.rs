@@ -0,0 +1,179 @@
+use std::{env, fs, path::PathBuf};
+
+use clap::{Parser, Subcommand};
+use ethbind_gen::{
+    ArtifactFormat, BindingBuilder, Config, Contract, Executor, Generator, JsonRuntimeBinder,
+};
+use ethbind_go::GoGenerator;
+use ethbind_json::{diff_abi, AbiField, FoundryArtifact, HardhatArtifact};
+use ethbind_markdown::MarkdownGenerator;
+use ethbind_rust::{CallMode, ModuleLayout, RustGenerator, RustPretty};
+use ethbind_solidity::SolidityGenerator;
+
+/// Ethereum contract binding code generator
+#[derive(Debug, Parser)]
+#[command(name = "ethbind", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Generate binding codes declared by `ethbind.toml`
+    Generate {
+        /// Config file path
+        #[arg(short, long, default_value = "ethbind.toml")]
+        config: PathBuf,
+        /// Target language, overrides `output.language`, one of `rust`, `go`, `solidity` or `markdown`
+        #[arg(short, long)]
+        language: Option<String>,
+        /// Output directory, overrides `output.dir`
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Compare two versions of contract abi, and report the breaking and non-breaking changes
+    Diff {
+        /// Old contract json file, raw abi, hardhat or foundry artifact
+        old: PathBuf,
+        /// New contract json file, raw abi, hardhat or foundry artifact
+        new: PathBuf,
+        /// Print the changes as json
+        #[arg(long)]
+        json: bool,
+        /// Exit with error if any change is breaking
+        #[arg(long)]
+        deny_breaking: bool,
+    },
+}
+
+fn main() -> anyhow::Result<()> {
+    _ = pretty_env_logger::try_init();
+
+    match Cli::parse().command {
+        Command::Generate {
+            config,
+            language,
+            output,
+        } => generate(config, language, output),
+        Command::Diff {
+            old,
+            new,
+            json,
+            deny_breaking,
+        } => diff(old, new, json, deny_breaking),
+    }
+}
+
+fn generate(
+    config: PathBuf,
+    language: Option<String>,
+    output: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let mut config = Config::load(config)?;
+
+    if let Some(output) = output {
+        config.output.dir = Some(env::current_dir()?.join(output));
+    }
+
+    let language = language
+        .or_else(|| config.option("language").map(|c| c.to_owned()))
+        .unwrap_or_else(|| "rust".to_owned());
+
+    let contracts = match language.as_str() {
+        "rust" => {
+            let layout = match config.option("layout") {
+                None | Some("modules") => ModuleLayout::Modules,
+                Some("inline") => ModuleLayout::Inline,
+                Some(layout) => return Err(anyhow::format_err!("Unknown rust layout {}", layout)),
+            };
+
+            let mut generator = RustGenerator::new(layout);
+
+            if config.flag("client_trait")?.unwrap_or(false) {
+                generator = generator.client_trait();
+            }
+
+            if config.flag("call_builder")?.unwrap_or(false) {
+                generator = generator.call_builder();
+            }
+
+            generator = match config.option("call_mode") {
+                None | Some("async") => generator,
+                Some("blocking") => generator.call_mode(CallMode::Blocking),
+                Some("both") => generator.call_mode(CallMode::Both),
+                Some(mode) => return Err(anyhow::format_err!("Unknown rust call mode {}", mode)),
+            };
+
+            let mut contracts = finalize(generator, &config)?;
+
+            if config.flag("pretty")?.unwrap_or(true) {
+                contracts.pretty()?;
+            }
+
+            contracts
+        }
+        "go" => finalize(
+            GoGenerator::new(config.option("package").unwrap_or("bindings")),
+            &config,
+        )?,
+        "solidity" => {
+            let mut generator = SolidityGenerator::default();
+
+            if let Some(license) = config.option("license") {
+                generator = generator.license(license);
+            }
+
+            if let Some(pragma) = config.option("pragma") {
+                generator = generator.pragma(pragma);
+            }
+
+            finalize(generator, &config)?
+        }
+        "markdown" => finalize(MarkdownGenerator::default(), &config)?,
+        _ => return Err(anyhow::format_err!("Unknown language {}", language)),
+    };
+
+    config.save(&contracts)?;
+
+    log::info!("generated {} contracts", config.contracts.len());
+
+    Ok(())
+}
+
+fn finalize<L: Generator>(generator: L, config: &Config) -> anyhow::Result<Vec<Contract>> {
+    BindingBuilder::<Executor<L, JsonRuntimeBinder>>::from_config(generator, config)?.finalize()
+}
+
+fn diff(old: PathBuf, new: PathBuf, json: bool, deny_breaking: bool) -> anyhow::Result<()> {
+    let diff = diff_abi(&load_abi(old)?, &load_abi(new)?);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&diff)?);
+    } else {
+        print!("{}", diff);
+    }
+
+    if deny_breaking && diff.is_breaking() {
+        return Err(anyhow::format_err!(
+            "Found {} breaking abi changes",
+            diff.breaking().count()
+        ));
+    }
+
+    Ok(())
+}
+
+/// Load abi of contract json file, the format is detected by [`ArtifactFormat::detect`]
+fn load_abi(path: PathBuf) -> anyhow::Result<Vec<AbiField>> {
+    let data = fs::read_to_string(&path)
+        .map_err(|err| anyhow::format_err!("Read {}: {}", path.display(), err))?;
+
+    let abi = match ArtifactFormat::detect(&data)? {
+        ArtifactFormat::Abi => serde_json::from_str(&data)?,
+        ArtifactFormat::Hardhat => serde_json::from_str::<HardhatArtifact>(&data)?.abi,
+        ArtifactFormat::Foundry => serde_json::from_str::<FoundryArtifact>(&data)?.abi,
+    };
+
+    Ok(abi)
+}
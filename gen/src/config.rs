@@ -0,0 +1,210 @@
+//! Declarative binding configuration, loaded from `ethbind.toml`, e.g:
+//!
+//! ```toml
+//! # Runtime types mapping file path, or an inline `[mapping]` table
+//! mapping = "mapping.json"
+//!
+//! [output]
+//! dir = "src/sol"
+//! clean = true
+//! # Generator specific options, interpreted by the generator
+//! language = "rust"
+//! layout = "modules"
+//! client_trait = true
+//!
+//! [[contracts]]
+//! path = "out/Lock.sol/Lock.json"
+//! format = "foundry"
+//! rename = "TimeLock"
+//! skip = ["withdraw", "transfer(address,uint256)"]
+//! derives = ["Debug", "Clone"]
+//! # Only generate listed functions and skip deploy methods
+//! filter = { functions = { allow = ["balanceOf", "0xa9059cbb"] }, skip = ["deploy"] }
+//! ```
+
+use std::{
+    collections::HashMap,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{AbiFilter, JsonRuntimeBinder, SaveTo};
+
+/// Root of `ethbind.toml` configuration
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Config {
+    /// Runtime types mapping, can be omitted if the generator doesn't use runtime types.
+    #[serde(default)]
+    pub mapping: MappingConfig,
+    /// Contracts to bind, in generation order
+    #[serde(default)]
+    pub contracts: Vec<ContractConfig>,
+    #[serde(default)]
+    pub output: OutputConfig,
+    /// Directory which relative paths are resolved against, the config file's parent directory by default.
+    #[serde(skip)]
+    pub base_dir: PathBuf,
+}
+
+/// Runtime types mapping declared by file path or inline table
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MappingConfig {
+    Path(PathBuf),
+    Inline(JsonRuntimeBinder),
+}
+
+impl Default for MappingConfig {
+    fn default() -> Self {
+        Self::Inline(Default::default())
+    }
+}
+
+/// Input contract file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ArtifactFormat {
+    /// Raw abi json array
+    Abi,
+    /// Hardhat artifact
+    Hardhat,
+    /// Foundry artifact
+    Foundry,
+}
+
+impl ArtifactFormat {
+    /// Detect format of contract json data, raw abi is a json array,
+    /// hardhat artifact contains `contractName` field, otherwise the data is treated as foundry artifact.
+    pub fn detect(data: &str) -> anyhow::Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(data)?;
+
+        if value.is_array() {
+            Ok(Self::Abi)
+        } else if value.get("contractName").is_some() {
+            Ok(Self::Hardhat)
+        } else {
+            Ok(Self::Foundry)
+        }
+    }
+}
+
+/// Per-contract binding configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContractConfig {
+    /// Contract json file path
+    pub path: PathBuf,
+    /// Contract file format, detected by [`ArtifactFormat::detect`] if omitted
+    pub format: Option<ArtifactFormat>,
+    /// Override generated contract name, the default name of raw abi file is the file stem.
+    pub rename: Option<String>,
+    /// Functions to skip, by name, full signature or selector
+    #[serde(default)]
+    pub skip: Vec<String>,
+    /// Functions/events/errors filter
+    #[serde(default)]
+    pub filter: AbiFilter,
+    #[serde(flatten)]
+    pub options: ContractOptions,
+}
+
+/// Per-contract generation options, interpreted by [`Generator::generate_options`](crate::Generator::generate_options)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContractOptions {
+    /// Extra derive macros for generated data types
+    #[serde(default)]
+    pub derives: Vec<String>,
+}
+
+/// Output settings
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct OutputConfig {
+    /// Output directory
+    pub dir: Option<PathBuf>,
+    /// Remove stale files of previous run, see [`SaveTo::clean_save_to`]
+    #[serde(default)]
+    pub clean: bool,
+    /// Generator specific options, e.g: `language`, `layout`, `package`, `client_trait = true`
+    #[serde(flatten)]
+    pub options: HashMap<String, toml::Value>,
+}
+
+impl FromStr for Config {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(toml::from_str(s)?)
+    }
+}
+
+impl Config {
+    /// Load config from `ethbind.toml` file, relative paths are resolved against the file's parent directory.
+    pub fn load<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let mut config: Config = read_to_string(path.as_ref())
+            .map_err(|err| anyhow::format_err!("Read {}: {}", path.as_ref().display(), err))?
+            .parse()?;
+
+        config.base_dir = path
+            .as_ref()
+            .parent()
+            .map(|c| c.to_path_buf())
+            .unwrap_or_default();
+
+        Ok(config)
+    }
+
+    /// Resolve `path` against [`base_dir`](Config::base_dir)
+    pub fn resolve<P: AsRef<Path>>(&self, path: P) -> PathBuf {
+        self.base_dir.join(path)
+    }
+
+    /// Load runtime types mapping declared by this config
+    pub fn runtime_binder(&self) -> anyhow::Result<JsonRuntimeBinder> {
+        match &self.mapping {
+            MappingConfig::Path(path) => JsonRuntimeBinder::load(self.resolve(path)),
+            MappingConfig::Inline(binder) => Ok(binder.clone()),
+        }
+    }
+
+    /// Returns generator specific output option, boolean value is returned as `"true"` or `"false"`
+    pub fn option(&self, name: &str) -> Option<&str> {
+        match self.output.options.get(name)? {
+            toml::Value::String(value) => Some(value),
+            toml::Value::Boolean(true) => Some("true"),
+            toml::Value::Boolean(false) => Some("false"),
+            _ => None,
+        }
+    }
+
+    /// Returns generator specific boolean output option, declared as `true` or `"true"`
+    pub fn flag(&self, name: &str) -> anyhow::Result<Option<bool>> {
+        match self.output.options.get(name) {
+            None => Ok(None),
+            Some(toml::Value::Boolean(value)) => Ok(Some(*value)),
+            Some(toml::Value::String(value)) if value == "true" => Ok(Some(true)),
+            Some(toml::Value::String(value)) if value == "false" => Ok(Some(false)),
+            Some(value) => Err(anyhow::format_err!(
+                "Output option {} expect boolean, got {}",
+                name,
+                value
+            )),
+        }
+    }
+
+    /// Save generated contracts to output directory.
+    pub fn save<S: SaveTo>(&self, contracts: &S) -> anyhow::Result<()> {
+        let dir = self
+            .output
+            .dir
+            .as_ref()
+            .ok_or_else(|| anyhow::format_err!("Output directory is not configured"))?;
+
+        if self.output.clean {
+            contracts.clean_save_to(self.resolve(dir))
+        } else {
+            contracts.save_to(self.resolve(dir))
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use ethbind_json::{to_hex, AbiField};
+use serde::{Deserialize, Serialize};
+
+use crate::BindError;
+
+/// Select contract items by name, full signature or selector, e.g: `transfer`, `transfer(address,uint256)` or `0xa9059cbb`.
+///
+/// Events are selected by topic instead of selector.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ItemFilter {
+    /// If not empty, only the listed items are generated
+    #[serde(default)]
+    pub allow: Vec<String>,
+    /// The listed items are not generated, takes precedence over `allow`
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+impl ItemFilter {
+    /// Allow item by name, full signature or selector
+    pub fn allow<S: AsRef<str>>(mut self, item: S) -> Self {
+        self.allow.push(item.as_ref().to_owned());
+        self
+    }
+
+    /// Deny item by name, full signature or selector
+    pub fn deny<S: AsRef<str>>(mut self, item: S) -> Self {
+        self.deny.push(item.as_ref().to_owned());
+        self
+    }
+
+    fn matches(item: &str, keys: &[String]) -> bool {
+        if item.starts_with("0x") {
+            keys.iter().any(|c| c.eq_ignore_ascii_case(item))
+        } else {
+            keys.iter().any(|c| c == item)
+        }
+    }
+
+    fn is_selected(&self, keys: &[String]) -> bool {
+        (self.allow.is_empty() || self.allow.iter().any(|c| Self::matches(c, keys)))
+            && !self.deny.iter().any(|c| Self::matches(c, keys))
+    }
+
+    /// Returns error if any listed item doesn't match one of `items`
+    fn check(&self, kind: &str, items: &[Vec<String>]) -> anyhow::Result<()> {
+        let unknown = self
+            .allow
+            .iter()
+            .chain(self.deny.iter())
+            .filter(|c| !items.iter().any(|keys| Self::matches(c, keys)))
+            .map(|c| c.as_str())
+            .collect::<Vec<_>>();
+
+        if unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(BindError::UnknownFilterItem(format!("{} {}", kind, unknown.join(", "))).into())
+        }
+    }
+}
+
+/// Contract item categories which can be skipped as a whole
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Category {
+    /// Deploy methods generated from constructor
+    Deploy,
+    Functions,
+    Events,
+    Errors,
+    Receive,
+    Fallback,
+}
+
+/// Per-contract abi items filter, applied before generation.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AbiFilter {
+    #[serde(default)]
+    pub functions: ItemFilter,
+    #[serde(default)]
+    pub events: ItemFilter,
+    #[serde(default)]
+    pub errors: ItemFilter,
+    /// Skipped categories
+    #[serde(default)]
+    pub skip: Vec<Category>,
+}
+
+impl AbiFilter {
+    /// Set functions filter
+    pub fn functions(mut self, filter: ItemFilter) -> Self {
+        self.functions = filter;
+        self
+    }
+
+    /// Set events filter
+    pub fn events(mut self, filter: ItemFilter) -> Self {
+        self.events = filter;
+        self
+    }
+
+    /// Set errors filter
+    pub fn errors(mut self, filter: ItemFilter) -> Self {
+        self.errors = filter;
+        self
+    }
+
+    /// Skip whole `category`
+    pub fn skip(mut self, category: Category) -> Self {
+        self.skip.push(category);
+        self
+    }
+
+    /// Returns matching keys of abi item: name, signature and selector/topic.
+    fn keys(field: &AbiField) -> Vec<String> {
+        match field {
+            AbiField::Function(function) => vec![
+                function.name.clone(),
+                function.signature(),
+                to_hex(function.selector()),
+            ],
+            AbiField::Event(event) => {
+                vec![event.name.clone(), event.signature(), to_hex(event.topic())]
+            }
+            AbiField::Error(error) => {
+                vec![
+                    error.name.clone(),
+                    error.signature(),
+                    to_hex(error.selector()),
+                ]
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Remove filtered items from `abi`, returns error if any listed item is not found in `abi`.
+    pub fn apply(&self, abi: &mut Vec<AbiField>) -> anyhow::Result<()> {
+        let keys_of = |f: fn(&AbiField) -> bool| {
+            abi.iter()
+                .filter(|c| f(c))
+                .map(Self::keys)
+                .collect::<Vec<_>>()
+        };
+
+        self.functions
+            .check("function", &keys_of(|c| matches!(c, AbiField::Function(_))))?;
+
+        self.events
+            .check("event", &keys_of(|c| matches!(c, AbiField::Event(_))))?;
+
+        self.errors
+            .check("error", &keys_of(|c| matches!(c, AbiField::Error(_))))?;
+
+        let skip = |category| self.skip.contains(&category);
+
+        abi.retain(|field| match field {
+            AbiField::Constructor(_) => !skip(Category::Deploy),
+            AbiField::Receive(_) => !skip(Category::Receive),
+            AbiField::Fallback(_) => !skip(Category::Fallback),
+            AbiField::Function(_) => {
+                !skip(Category::Functions) && self.functions.is_selected(&Self::keys(field))
+            }
+            AbiField::Event(_) => {
+                !skip(Category::Events) && self.events.is_selected(&Self::keys(field))
+            }
+            AbiField::Error(_) => {
+                !skip(Category::Errors) && self.errors.is_selected(&Self::keys(field))
+            }
+        });
+
+        Ok(())
+    }
+}
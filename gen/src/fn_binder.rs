@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use ethbind_json::{FixedMN, IntegerM, SimpleType, Type};
+
+use crate::{BindError, RuntimeBinder};
+
+type SimpleFn = Box<dyn Fn(&SimpleType) -> String>;
+type BytesMFn = Box<dyn Fn(usize) -> String>;
+type IntegerMFn = Box<dyn Fn(&IntegerM) -> String>;
+type FixedMNFn = Box<dyn Fn(&FixedMN) -> String>;
+type ArrayFn = Box<dyn Fn(&str) -> String>;
+type ArrayMFn = Box<dyn Fn(&str, usize) -> String>;
+type TupleFn = Box<dyn Fn(&[Type]) -> Option<String>>;
+type KeyFn = Box<dyn Fn() -> String>;
+
+/// A [`RuntimeBinder`] implementation which maps abi types by closures registered per type family and per runtime key.
+///
+/// Closures return owned values, e.g: [`String`] or `TokenStream`, the results are cached by the binder.
+///
+/// ```
+/// # use ethbind_gen::{FnRuntimeBinder, RuntimeBinder};
+/// let mut runtime_binder = FnRuntimeBinder::default()
+///     .integer_m(|c| match c.m {
+///         8 | 16 | 32 | 64 | 128 if c.signed => format!("i{}", c.m),
+///         8 | 16 | 32 | 64 | 128 => format!("u{}", c.m),
+///         _ => "U256".to_owned(),
+///     })
+///     .array(|el| format!("Vec<{}>", el))
+///     .key("rt_error", || "anyhow::Error");
+///
+/// let t = "uint8[]".parse().unwrap();
+///
+/// assert_eq!(runtime_binder.to_runtime_type(&t).unwrap(), Some("Vec<u8>"));
+/// assert_eq!(runtime_binder.get("rt_error").unwrap(), "anyhow::Error");
+/// ```
+#[derive(Default)]
+pub struct FnRuntimeBinder {
+    simple: Option<SimpleFn>,
+    bytes_m: Option<BytesMFn>,
+    integer_m: Option<IntegerMFn>,
+    fixed_m_n: Option<FixedMNFn>,
+    array: Option<ArrayFn>,
+    array_m: Option<ArrayMFn>,
+    tuple: Option<TupleFn>,
+    keys: HashMap<String, KeyFn>,
+    /// Cached runtime types, indexed by abi type
+    types_cache: HashMap<String, String>,
+    /// Cached runtime key values, indexed by key name
+    keys_cache: HashMap<String, String>,
+}
+
+impl FnRuntimeBinder {
+    /// Map `address`, `bool`, `bytes`, `string` and `function` types.
+    pub fn simple<F, T>(mut self, f: F) -> Self
+    where
+        F: Fn(&SimpleType) -> T + 'static,
+        T: ToString,
+    {
+        self.simple = Some(Box::new(move |c| f(c).to_string()));
+        self
+    }
+
+    /// Map `bytes<M>` types.
+    pub fn bytes_m<F, T>(mut self, f: F) -> Self
+    where
+        F: Fn(usize) -> T + 'static,
+        T: ToString,
+    {
+        self.bytes_m = Some(Box::new(move |c| f(c).to_string()));
+        self
+    }
+
+    /// Map `int<M>`/`uint<M>` types, `int`/`uint` are mapped as `int256`/`uint256`.
+    pub fn integer_m<F, T>(mut self, f: F) -> Self
+    where
+        F: Fn(&IntegerM) -> T + 'static,
+        T: ToString,
+    {
+        self.integer_m = Some(Box::new(move |c| f(c).to_string()));
+        self
+    }
+
+    /// Map `fixed<M>x<N>`/`ufixed<M>x<N>` types, `fixed`/`ufixed` are mapped as `fixed128x18`/`ufixed128x18`.
+    pub fn fixed_m_n<F, T>(mut self, f: F) -> Self
+    where
+        F: Fn(&FixedMN) -> T + 'static,
+        T: ToString,
+    {
+        self.fixed_m_n = Some(Box::new(move |c| f(c).to_string()));
+        self
+    }
+
+    /// Map `<type>[]` types, the closure is called with the element runtime type.
+    pub fn array<F, T>(mut self, f: F) -> Self
+    where
+        F: Fn(&str) -> T + 'static,
+        T: ToString,
+    {
+        self.array = Some(Box::new(move |c| f(c).to_string()));
+        self
+    }
+
+    /// Map `<type>[M]` types, the closure is called with the element runtime type and `M`.
+    pub fn array_m<F, T>(mut self, f: F) -> Self
+    where
+        F: Fn(&str, usize) -> T + 'static,
+        T: ToString,
+    {
+        self.array_m = Some(Box::new(move |c, m| f(c, m).to_string()));
+        self
+    }
+
+    /// Map tuple types, the closure is called with the component types, which is empty for bare `tuple` type.
+    ///
+    /// If the closure returns `None` or is not registered, the generator declares struct for tuple.
+    pub fn tuple<F, T>(mut self, f: F) -> Self
+    where
+        F: Fn(&[Type]) -> Option<T> + 'static,
+        T: ToString,
+    {
+        self.tuple = Some(Box::new(move |c| f(c).map(|c| c.to_string())));
+        self
+    }
+
+    /// Register runtime key, e.g: `rt_client`, `rt_error`.
+    pub fn key<S, F, T>(mut self, name: S, f: F) -> Self
+    where
+        S: AsRef<str>,
+        F: Fn() -> T + 'static,
+        T: ToString,
+    {
+        self.keys
+            .insert(name.as_ref().to_owned(), Box::new(move || f().to_string()));
+        self
+    }
+
+    /// Returns the registered closure name required by `type`, if it is not registered.
+    fn missing_key(&self, r#type: &Type) -> Option<&'static str> {
+        match r#type {
+            Type::Simple(SimpleType::Tuple) | Type::Tuple(_) => None,
+            Type::Simple(SimpleType::Uint) | Type::Simple(SimpleType::Int) | Type::IntegerM(_) => {
+                self.integer_m.is_none().then_some("integer_m")
+            }
+            Type::Simple(SimpleType::Fixed)
+            | Type::Simple(SimpleType::Ufixed)
+            | Type::FixedMN(_) => self.fixed_m_n.is_none().then_some("fixed_m_n"),
+            Type::Simple(_) => self.simple.is_none().then_some("simple"),
+            Type::BytesM(_) => self.bytes_m.is_none().then_some("bytes_m"),
+            Type::Array(_) => self.array.is_none().then_some("array"),
+            Type::ArrayM(_) => self.array_m.is_none().then_some("array_m"),
+        }
+    }
+
+    /// Map `type` without cache.
+    fn map(&mut self, r#type: &Type) -> anyhow::Result<Option<String>> {
+        if let Some(key) = self.missing_key(r#type) {
+            return Err(BindError::UnknownType(format!("{} of {}", key, r#type)).into());
+        }
+
+        let runtime_type = match r#type {
+            Type::Simple(SimpleType::Tuple) => self.tuple.as_ref().and_then(|f| f(&[])),
+            Type::Tuple(types) => self.tuple.as_ref().and_then(|f| f(types)),
+            Type::Simple(SimpleType::Uint) => self.integer_m.as_ref().map(|f| {
+                f(&IntegerM {
+                    signed: false,
+                    m: 256,
+                })
+            }),
+            Type::Simple(SimpleType::Int) => self.integer_m.as_ref().map(|f| {
+                f(&IntegerM {
+                    signed: true,
+                    m: 256,
+                })
+            }),
+            Type::Simple(SimpleType::Fixed) => self.fixed_m_n.as_ref().map(|f| {
+                f(&FixedMN {
+                    m: 128,
+                    n: 18,
+                    signed: true,
+                })
+            }),
+            Type::Simple(SimpleType::Ufixed) => self.fixed_m_n.as_ref().map(|f| {
+                f(&FixedMN {
+                    m: 128,
+                    n: 18,
+                    signed: false,
+                })
+            }),
+            Type::Simple(simple) => self.simple.as_ref().map(|f| f(simple)),
+            Type::BytesM(bytes_m) => self.bytes_m.as_ref().map(|f| f(bytes_m.m)),
+            Type::IntegerM(integer_m) => self.integer_m.as_ref().map(|f| f(integer_m)),
+            Type::FixedMN(fixed_m_n) => self.fixed_m_n.as_ref().map(|f| f(fixed_m_n)),
+            Type::Array(array) => {
+                let el = match self.to_runtime_type(&array.element)? {
+                    Some(el) => el.to_owned(),
+                    None => return Ok(None),
+                };
+
+                self.array.as_ref().map(|f| f(&el))
+            }
+            Type::ArrayM(array_m) => {
+                let el = match self.to_runtime_type(&array_m.element)? {
+                    Some(el) => el.to_owned(),
+                    None => return Ok(None),
+                };
+
+                self.array_m.as_ref().map(|f| f(&el, array_m.m))
+            }
+        };
+
+        Ok(runtime_type)
+    }
+}
+
+impl RuntimeBinder for FnRuntimeBinder {
+    fn to_runtime_type(&mut self, r#type: &Type) -> anyhow::Result<Option<&str>> {
+        let tag = r#type.to_string();
+
+        if !self.types_cache.contains_key(&tag) {
+            match self.map(r#type)? {
+                Some(runtime_type) => {
+                    self.types_cache.insert(tag.clone(), runtime_type);
+                }
+                None => return Ok(None),
+            }
+        }
+
+        Ok(self.types_cache.get(&tag).map(|c| c.as_str()))
+    }
+
+    /// Returns the registered runtime key `name`, or the runtime type of abi type `name`, e.g: `address`.
+    fn get(&mut self, name: &str) -> anyhow::Result<&str> {
+        if !self.keys.contains_key(name) {
+            let r#type: Type = name
+                .parse()
+                .map_err(|_| BindError::UnknownType(name.to_string()))?;
+
+            return self
+                .to_runtime_type(&r#type)?
+                .ok_or_else(|| BindError::UnknownType(name.to_string()).into());
+        }
+
+        if !self.keys_cache.contains_key(name) {
+            let value = self.keys[name]();
+
+            self.keys_cache.insert(name.to_owned(), value);
+        }
+
+        Ok(self.keys_cache.get(name).unwrap())
+    }
+
+    fn missing_keys(&self, r#type: &Type) -> Vec<String> {
+        let mut keys = vec![];
+
+        let mut r#type = r#type;
+
+        loop {
+            keys.extend(self.missing_key(r#type).map(|c| c.to_owned()));
+
+            r#type = match r#type {
+                Type::Array(array) => &array.element,
+                Type::ArrayM(array_m) => &array_m.element,
+                _ => return keys,
+            };
+        }
+    }
+}
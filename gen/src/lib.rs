@@ -4,26 +4,68 @@
 //!
 
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fs::{self, read_to_string},
-    path::Path,
+    path::{Path, PathBuf},
     str::FromStr,
 };
 
+use anyhow::Context as _;
 use ethbind_json::{
-    AbiField, Array, ArrayM, Constructor, Error, Event, FixedMN, Function, HardhatArtifact,
-    IntegerM, Type,
+    AbiField, Array, ArrayM, Constructor, Error, Event, Fallback, FixedMN, FoundryArtifact,
+    Function, HardhatArtifact, IntegerM, NatSpec, Parameter, Receive, SimpleType, Type,
 };
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+mod config;
+pub use config::*;
+
+mod filter;
+pub use filter::*;
+
+mod fn_binder;
+pub use fn_binder::*;
+
+mod preset;
+
 /// Typed **bind** error
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum BindError {
     /// This error is returned by [`RuntimeBinder`] to indicate
     /// that a runtime type binding for the contract type was not found.
     #[error("Runtime binder didn't found mapping runtime type for {0}")]
     UnknownType(String),
+
+    /// This error is returned by [`AbiFilter`] to indicate
+    /// that a filter item doesn't match any contract item.
+    #[error("Filter item not found: {0}")]
+    UnknownFilterItem(String),
+
+    /// This error is returned by [`BindingBuilder`] to indicate
+    /// that the runtime binder doesn't cover all types used by the bound abis.
+    #[error("Runtime binder missing mapping keys: {0}")]
+    MissingKeys(String),
+
+    /// This error is returned by [`JsonRuntimeBinder::preset`] to indicate
+    /// that no bundled preset matches the name.
+    #[error("Unknown runtime types preset: {0}")]
+    UnknownPreset(String),
+
+    /// This error is returned by [`Generator`] to indicate
+    /// that a mapped runtime type is not valid in the target language.
+    #[error("Invalid runtime type: {0}")]
+    InvalidRuntimeType(String),
+
+    /// This error is returned by [`JsonRuntimeBinder`] to indicate
+    /// that an integer width range rule is malformed, e.g: `uint8..64`.
+    #[error("Invalid integer width range rule: {0}")]
+    InvalidWidthRange(String),
+
+    /// This error is returned by [`BindingBuilder`] to indicate
+    /// that [`filter`](BindingBuilder::filter) is called before any contract is bound.
+    #[error("Call bind_* before {0}")]
+    NotBound(String),
 }
 
 /// ABI data structure that can be generated into arbitrary programming language supported by `Ethbind`.
@@ -57,6 +99,14 @@ pub trait RuntimeBinder {
 
     /// Get runtime type by metadata `name`, If not found the implementation must return [`Err(BindError::UnknownType)`]
     fn get(&mut self, name: &str) -> anyhow::Result<&str>;
+
+    /// Returns mapping keys required by `type` which this binder doesn't define,
+    /// [`BindingBuilder`] calls this fn to check every type used by the bound abis before generation starts.
+    ///
+    /// The default implementation reports nothing.
+    fn missing_keys(&self, _type: &Type) -> Vec<String> {
+        vec![]
+    }
 }
 
 /// Programming language code generator supported by `Ethbind`.
@@ -99,6 +149,58 @@ pub trait Generator {
         error: &Error,
     ) -> anyhow::Result<()>;
 
+    /// Generate receive function interface ,call this fn after call [`begin`](Generator::begin) at least once.
+    ///
+    /// The default implementation skip generation step, end users do not directly call receive api.
+    fn generate_receive<R: RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        _receive: &Receive,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Generate fallback function interface ,call this fn after call [`begin`](Generator::begin) at least once.
+    ///
+    /// The default implementation skip generation step, end users do not directly call fallback api.
+    fn generate_fallback<R: RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        _fallback: &Fallback,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Attach contract `NatSpec` documentation to current generation round,
+    /// [`Generatable`] call this fn right after [`begin`](Generator::begin) if the input artifact contains `userdoc`/`devdoc`.
+    ///
+    /// The default implementation ignores the documentation.
+    fn generate_natspec<R: RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        _natspec: &NatSpec,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Apply per-contract [`options`](ContractOptions) to current generation round,
+    /// [`BindingBuilder::from_config`] call this fn right after [`begin`](Generator::begin).
+    ///
+    /// The default implementation ignores the options.
+    fn generate_options<R: RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        _options: &ContractOptions,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Returns false if this generator doesn't map abi types via [`RuntimeBinder`],
+    /// in which case [`BindingBuilder`] skips the runtime types validation step.
+    fn uses_runtime_types(&self) -> bool {
+        true
+    }
+
     /// Close generator and return generated contract codes.
     fn finalize<R: RuntimeBinder>(self, runtime_binder: &mut R) -> anyhow::Result<Vec<Contract>>;
 }
@@ -115,7 +217,56 @@ pub struct File {
 }
 
 pub trait SaveTo {
+    /// Write generated codes to `output_dir`, exists files with the same name are overwritten.
     fn save_to<P: AsRef<Path>>(&self, output_dir: P) -> anyhow::Result<()>;
+
+    /// Write generated codes to `output_dir` and remove stale files generated by previous run.
+    ///
+    /// A file is stale if it isn't generated this time and has the same extension as one of generated files,
+    /// subdirectories and files with other extensions are kept.
+    fn clean_save_to<P: AsRef<Path>>(&self, output_dir: P) -> anyhow::Result<()>;
+}
+
+/// Remove files in `output_dir` which are not listed in `files` but have the same extension as one of them.
+fn remove_stale_files<'a, P: AsRef<Path>, I: IntoIterator<Item = &'a File>>(
+    output_dir: P,
+    files: I,
+) -> anyhow::Result<()> {
+    if !output_dir.as_ref().exists() {
+        return Ok(());
+    }
+
+    let files = files.into_iter().collect::<Vec<_>>();
+
+    let extensions = files
+        .iter()
+        .filter_map(|c| Path::new(&c.name).extension())
+        .collect::<Vec<_>>();
+
+    for entry in fs::read_dir(&output_dir)? {
+        let path = entry?.path();
+
+        if !path.is_file() {
+            continue;
+        }
+
+        let generated = files
+            .iter()
+            .any(|c| path.file_name() == Path::new(&c.name).file_name());
+
+        let stale = path
+            .extension()
+            .map(|c| extensions.contains(&c))
+            .unwrap_or(false);
+
+        if !generated && stale {
+            log::debug!("remove stale file {}", path.display());
+
+            fs::remove_file(path)?;
+        }
+    }
+
+    Ok(())
 }
 
 impl SaveTo for Contract {
@@ -133,6 +284,12 @@ impl SaveTo for Contract {
 
         Ok(())
     }
+
+    fn clean_save_to<P: AsRef<Path>>(&self, output_dir: P) -> anyhow::Result<()> {
+        remove_stale_files(&output_dir, &self.files)?;
+
+        self.save_to(output_dir)
+    }
 }
 
 impl SaveTo for Vec<Contract> {
@@ -143,10 +300,44 @@ impl SaveTo for Vec<Contract> {
 
         Ok(())
     }
+
+    fn clean_save_to<P: AsRef<Path>>(&self, output_dir: P) -> anyhow::Result<()> {
+        remove_stale_files(&output_dir, self.iter().flat_map(|c| &c.files))?;
+
+        self.save_to(output_dir)
+    }
+}
+
+/// Type mapping keys of [`JsonRuntimeBinder`], besides the `rt_*` runtime keys and integer rules, e.g: `uint256`, `uint8..=64`.
+pub const TYPE_MAPPING_KEYS: &[&str] = &[
+    "address",
+    "bool",
+    "bytes",
+    "string",
+    "function",
+    "uint_m",
+    "int_m",
+    "fixed_m_n",
+    "ufixed_m_n",
+    "bytes_m",
+    "array",
+    "array_m",
+];
+
+/// Returns true if `key` is a [`JsonRuntimeBinder`] mapping key which is a valid identifier,
+/// e.g: `address`, `rt_client` or integer override `uint256`.
+pub fn is_mapping_key(key: &str) -> bool {
+    TYPE_MAPPING_KEYS.contains(&key)
+        || key.starts_with("rt_")
+        || matches!(key.parse::<Type>(), Ok(Type::IntegerM(_)))
 }
 
 /// A [`RuntimeBinder`] implementation which load runtime types mapping metadata from json.
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// Integer types can be mapped by exact override (`"uint256": "U256"`) or width range rule
+/// (`"uint8..=64": "u$m"`, `"uint136..": "U256"`), falling back to the `uint_m`/`int_m` templates.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(try_from = "HashMap<String, String>")]
 pub struct JsonRuntimeBinder {
     #[serde(flatten)]
     runtime_types: HashMap<String, String>,
@@ -167,7 +358,7 @@ impl TryFrom<&str> for JsonRuntimeBinder {
     type Error = serde_json::Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        serde_json::from_str(value.as_ref())
+        serde_json::from_str(value)
     }
 }
 
@@ -187,6 +378,7 @@ impl TryFrom<&[u8]> for JsonRuntimeBinder {
     }
 }
 
+#[allow(clippy::wrong_self_convention)]
 impl JsonRuntimeBinder {
     /// Create `JsonRuntimeBinder` from [`Reader`](std::io::Read)
     pub fn from_reader<R>(reader: R) -> anyhow::Result<Self>
@@ -201,6 +393,71 @@ impl JsonRuntimeBinder {
         Ok(fs::read_to_string(path)?.try_into()?)
     }
 
+    /// Collect mapping keys required to map `type`, tuple type requires no key, its components are checked separately.
+    fn required_keys(&self, r#type: &Type, keys: &mut Vec<&'static str>) {
+        match r#type {
+            Type::IntegerM(integer_m) if self.search_integer_m(integer_m).is_some() => {}
+            Type::Simple(SimpleType::Tuple) | Type::Tuple(_) => {}
+            Type::Simple(SimpleType::Address) => keys.push("address"),
+            Type::Simple(SimpleType::Bool) => keys.push("bool"),
+            Type::Simple(SimpleType::Bytes) => keys.push("bytes"),
+            Type::Simple(SimpleType::String) => keys.push("string"),
+            Type::Simple(SimpleType::Function) => keys.push("function"),
+            // Aliases of `uint256`/`int256`
+            Type::Simple(SimpleType::Uint) => self.required_keys(
+                &Type::IntegerM(IntegerM {
+                    signed: false,
+                    m: 256,
+                }),
+                keys,
+            ),
+            Type::Simple(SimpleType::Int) => self.required_keys(
+                &Type::IntegerM(IntegerM {
+                    signed: true,
+                    m: 256,
+                }),
+                keys,
+            ),
+            Type::Simple(SimpleType::Fixed) => keys.push("fixed_m_n"),
+            Type::Simple(SimpleType::Ufixed) => keys.push("ufixed_m_n"),
+            Type::BytesM(_) => keys.push("bytes_m"),
+            Type::IntegerM(integer_m) if integer_m.signed => keys.push("int_m"),
+            Type::IntegerM(_) => keys.push("uint_m"),
+            Type::FixedMN(fixed) if fixed.signed => keys.push("fixed_m_n"),
+            Type::FixedMN(_) => keys.push("ufixed_m_n"),
+            Type::Array(array) => {
+                keys.push("array");
+                self.required_keys(&array.element, keys);
+            }
+            Type::ArrayM(array_m) => {
+                keys.push("array_m");
+                self.required_keys(&array_m.element, keys);
+            }
+        }
+    }
+
+    /// Search integer type's exact override or width range rule, e.g: `uint256` or `uint8..=64`, `uint136..`.
+    ///
+    /// The exact override takes precedence, if more than one range rule contains the width, the narrowest one is used.
+    fn search_integer_m(&self, integer_m: &IntegerM) -> Option<&str> {
+        if let Some(runtime_type) = self.runtime_types.get(&integer_m.to_string()) {
+            return Some(runtime_type);
+        }
+
+        let prefix = if integer_m.signed { "int" } else { "uint" };
+
+        self.runtime_types
+            .iter()
+            .filter_map(|(key, runtime_type)| {
+                let (start, end) = parse_width_range(key.strip_prefix(prefix)?)?;
+
+                (start <= integer_m.m && integer_m.m <= end)
+                    .then_some(((end - start, start), runtime_type.as_str()))
+            })
+            .min_by_key(|(span, _)| *span)
+            .map(|(_, runtime_type)| runtime_type)
+    }
+
     /// Try map contract abi basic types's `type_name` to [`runtime type`](JsonRuntimeType), if not found returns `Err(EthBindError::UnknownType)`
     fn search_basic_type<T: AsRef<str>>(&self, type_name: T) -> anyhow::Result<&str> {
         self.runtime_types
@@ -217,7 +474,7 @@ impl JsonRuntimeBinder {
             return Ok(self.components.get(&tag).map(|c| c.as_str()));
         }
 
-        let el_type = { self.to_runtime_type(&element.element)?.map(|c| c.clone()) };
+        let el_type = { self.to_runtime_type(&element.element)? };
 
         if el_type.is_none() {
             return Ok(None);
@@ -263,7 +520,7 @@ impl JsonRuntimeBinder {
             return Ok(self.components.get(&tag).map(|c| c.as_str()));
         }
 
-        let el_type = { self.to_runtime_type(&element.element)?.map(|c| c.clone()) };
+        let el_type = { self.to_runtime_type(&element.element)? };
 
         if el_type.is_none() {
             return Ok(None);
@@ -288,7 +545,11 @@ impl JsonRuntimeBinder {
             return Ok(self.components.get(&tag).unwrap());
         }
 
-        let runtime_type = self.search_basic_type("fixed_m_n")?;
+        let runtime_type = if fixed.signed {
+            self.search_basic_type("fixed_m_n")?
+        } else {
+            self.search_basic_type("ufixed_m_n")?
+        };
 
         let declare_type = runtime_type
             .replace("$m", &fixed.m.to_string())
@@ -307,10 +568,10 @@ impl JsonRuntimeBinder {
             return Ok(self.components.get(&tag).unwrap());
         }
 
-        let runtime_type = if integer_m.signed {
-            self.search_basic_type("int_m")?
-        } else {
-            self.search_basic_type("uint_m")?
+        let runtime_type = match self.search_integer_m(integer_m) {
+            Some(runtime_type) => runtime_type,
+            None if integer_m.signed => self.search_basic_type("int_m")?,
+            None => self.search_basic_type("uint_m")?,
         };
 
         let declare_type = runtime_type.replace("$m", &integer_m.m.to_string());
@@ -324,13 +585,40 @@ impl JsonRuntimeBinder {
 impl RuntimeBinder for JsonRuntimeBinder {
     fn to_runtime_type(&mut self, r#type: &Type) -> anyhow::Result<Option<&str>> {
         match r#type {
-            Type::Simple(element) if element.is_tuple() => Ok(None),
-            Type::Simple(element) => self.search_basic_type(element.to_string()).map(|c| Some(c)),
-            Type::ArrayM(element) => self.to_array_m(&element),
-            Type::Array(element) => self.to_array(&element),
-            Type::BytesM(element) => self.to_bytes_m(element.m).map(|c| Some(c)),
-            Type::FixedMN(element) => self.to_fixed_m_n(element).map(|c| Some(c)),
-            Type::IntegerM(element) => self.to_integer_m(element).map(|c| Some(c)),
+            Type::Simple(SimpleType::Tuple) | Type::Tuple(_) => Ok(None),
+            // Aliases of `uint256`/`int256`/`fixed128x18`/`ufixed128x18`
+            Type::Simple(SimpleType::Uint) => self
+                .to_integer_m(&IntegerM {
+                    signed: false,
+                    m: 256,
+                })
+                .map(Some),
+            Type::Simple(SimpleType::Int) => self
+                .to_integer_m(&IntegerM {
+                    signed: true,
+                    m: 256,
+                })
+                .map(Some),
+            Type::Simple(SimpleType::Fixed) => self
+                .to_fixed_m_n(&FixedMN {
+                    m: 128,
+                    n: 18,
+                    signed: true,
+                })
+                .map(Some),
+            Type::Simple(SimpleType::Ufixed) => self
+                .to_fixed_m_n(&FixedMN {
+                    m: 128,
+                    n: 18,
+                    signed: false,
+                })
+                .map(Some),
+            Type::Simple(element) => self.search_basic_type(element.to_string()).map(Some),
+            Type::ArrayM(element) => self.to_array_m(element),
+            Type::Array(element) => self.to_array(element),
+            Type::BytesM(element) => self.to_bytes_m(element.m).map(Some),
+            Type::FixedMN(element) => self.to_fixed_m_n(element).map(Some),
+            Type::IntegerM(element) => self.to_integer_m(element).map(Some),
         }
     }
 
@@ -340,6 +628,17 @@ impl RuntimeBinder for JsonRuntimeBinder {
             .get(name)
             .ok_or(BindError::UnknownType(name.to_string()))?)
     }
+
+    fn missing_keys(&self, r#type: &Type) -> Vec<String> {
+        let mut keys = vec![];
+
+        self.required_keys(r#type, &mut keys);
+
+        keys.into_iter()
+            .filter(|c| !self.runtime_types.contains_key(*c))
+            .map(|c| c.to_owned())
+            .collect()
+    }
 }
 
 /// `Ethbind` executor
@@ -378,16 +677,215 @@ where
     }
 }
 
+/// Deferred loading step registered by `BindingBuilder::bind_*` fns, returns contract name and contract data.
+type Loader = Box<dyn Fn() -> anyhow::Result<(String, Artifact)>>;
+
+/// Contract binding registered by `BindingBuilder::bind_*` fns
+struct Binding {
+    load: Loader,
+    options: ContractOptions,
+    /// Applied to contract abi before generation
+    filter: AbiFilter,
+}
+
+/// Contract data loaded by binding step
+#[derive(Debug)]
+enum Artifact {
+    Abi(Vec<AbiField>),
+    Hardhat(HardhatArtifact),
+    Foundry(Box<FoundryArtifact>),
+}
+
+impl Artifact {
+    /// Parse artifact `data`, the error names the abi item and parameter which failed to parse if possible.
+    fn parse(format: ArtifactFormat, data: &str) -> anyhow::Result<Self> {
+        let artifact = match format {
+            ArtifactFormat::Abi => serde_json::from_str(data).map(Self::Abi),
+            ArtifactFormat::Hardhat => serde_json::from_str(data).map(Self::Hardhat),
+            ArtifactFormat::Foundry => serde_json::from_str(data).map(Self::Foundry),
+        };
+
+        artifact.map_err(|err| match locate_abi_error(format, data) {
+            Some(location) => anyhow::format_err!("Parse {}: {}", location, err),
+            None => err.into(),
+        })
+    }
+
+    /// Returns contract name declared by artifact, raw abi doesn't contain contract name.
+    fn contract_name(&self) -> Option<&str> {
+        match self {
+            Self::Abi(_) => None,
+            Self::Hardhat(artifact) => Some(&artifact.contract_name),
+            Self::Foundry(artifact) => artifact.contract_name(),
+        }
+    }
+
+    fn abi(&self) -> &[AbiField] {
+        match self {
+            Self::Abi(abi) => abi,
+            Self::Hardhat(artifact) => &artifact.abi,
+            Self::Foundry(artifact) => &artifact.abi,
+        }
+    }
+
+    fn abi_mut(&mut self) -> &mut Vec<AbiField> {
+        match self {
+            Self::Abi(abi) => abi,
+            Self::Hardhat(artifact) => &mut artifact.abi,
+            Self::Foundry(artifact) => &mut artifact.abi,
+        }
+    }
+}
+
+impl Generatable for Artifact {
+    fn generate<C: Context>(&self, context: &mut C) -> anyhow::Result<()> {
+        match self {
+            Self::Abi(abi) => abi.generate(context),
+            Self::Hardhat(artifact) => artifact.generate(context),
+            Self::Foundry(artifact) => artifact.generate(context),
+        }
+    }
+}
+
+/// Returns the first abi item and parameter of artifact `data` which failed to parse,
+/// e.g: ``function `register` parameter `owner` ``.
+fn locate_abi_error(format: ArtifactFormat, data: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(data).ok()?;
+
+    let abi = match format {
+        ArtifactFormat::Abi => &value,
+        ArtifactFormat::Hardhat | ArtifactFormat::Foundry => value.get("abi")?,
+    };
+
+    let item = abi
+        .as_array()?
+        .iter()
+        .find(|c| serde_json::from_value::<AbiField>((*c).clone()).is_err())?;
+
+    let kind = item.get("type").and_then(|c| c.as_str()).unwrap_or("item");
+
+    let item_desc = match item.get("name").and_then(|c| c.as_str()) {
+        Some(name) => format!("{} `{}`", kind, name),
+        None => kind.to_owned(),
+    };
+
+    for (field, label) in [("inputs", "parameter"), ("outputs", "output")] {
+        let params = item.get(field).and_then(|c| c.as_array());
+
+        for (index, param) in params.into_iter().flatten().enumerate() {
+            if serde_json::from_value::<Parameter>(param.clone()).is_ok() {
+                continue;
+            }
+
+            return match param.get("name").and_then(|c| c.as_str()) {
+                Some(name) if !name.is_empty() => {
+                    Some(format!("{} {} `{}`", item_desc, label, name))
+                }
+                _ => Some(format!("{} {} #{}", item_desc, label, index)),
+            };
+        }
+    }
+
+    Some(item_desc)
+}
+
+/// Returns file stem of `path` as fallback contract name
+fn to_contract_name(path: &Path) -> anyhow::Result<String> {
+    path.file_stem()
+        .and_then(|c| c.to_str())
+        .map(|c| c.to_owned())
+        .ok_or_else(|| anyhow::format_err!("Can't resolve contract name of {:?}", path))
+}
+
+/// Returns true if mapping `key` looks like an integer width range rule, e.g: `uint8..=64` or `int136..`.
+fn is_width_range_key(key: &str) -> bool {
+    (key.starts_with("uint") || key.starts_with("int")) && key.contains("..")
+}
+
+/// Parse integer width range rule, e.g: `8..=64` or `136..`
+///
+/// Returns `None` if the widths are not `8..=256` bits multiple of 8 or the range is empty.
+fn parse_width_range(range: &str) -> Option<(usize, usize)> {
+    let is_width = |m: usize| (8..=256).contains(&m) && m.is_multiple_of(8);
+
+    let (start, end) = if let Some((start, end)) = range.split_once("..=") {
+        let end = end.parse().ok()?;
+
+        (start.parse().ok()?, is_width(end).then_some(end)?)
+    } else {
+        (range.strip_suffix("..")?.parse().ok()?, usize::MAX)
+    };
+
+    (is_width(start) && start <= end).then_some((start, end))
+}
+
+/// Call `f` with every parameter of `abi`, including tuple components,
+/// and the parameter location, e.g: ``transfer parameter `to` `` or `balanceOf output #0`.
+fn for_each_param<F: FnMut(&Parameter, &dyn Fn() -> String)>(abi: &[AbiField], f: &mut F) {
+    fn visit<F: FnMut(&Parameter, &dyn Fn() -> String)>(
+        item: &str,
+        kind: &str,
+        params: &[Parameter],
+        f: &mut F,
+    ) {
+        for (index, param) in params.iter().enumerate() {
+            let location = || {
+                if param.name.is_empty() {
+                    format!("{} {} #{}", item, kind, index)
+                } else {
+                    format!("{} {} `{}`", item, kind, param.name)
+                }
+            };
+
+            f(param, &location);
+
+            if let Some(components) = &param.components {
+                visit(item, kind, components, f);
+            }
+        }
+    }
+
+    for field in abi {
+        match field {
+            AbiField::Function(function) => {
+                visit(&function.name, "parameter", &function.inputs, f);
+                visit(&function.name, "output", &function.outputs, f);
+            }
+            AbiField::Constructor(constructor) => {
+                visit("constructor", "parameter", &constructor.inputs, f)
+            }
+            AbiField::Event(event) => visit(&event.name, "field", &event.inputs, f),
+            AbiField::Error(error) => visit(&error.name, "field", &error.inputs, f),
+            AbiField::Receive(_) | AbiField::Fallback(_) => {}
+        }
+    }
+}
+
+/// Returns description of `field` used by generation error context, e.g: ``function `transfer(address,uint256)` ``.
+fn to_item_desc(field: &AbiField) -> String {
+    match field {
+        AbiField::Function(function) => format!("function `{}`", function.signature()),
+        AbiField::Constructor(constructor) => format!("`{}`", constructor.signature()),
+        AbiField::Event(event) => format!("event `{}`", event.signature()),
+        AbiField::Error(error) => format!("error `{}`", error.signature()),
+        AbiField::Receive(_) => "receive function".to_owned(),
+        AbiField::Fallback(_) => "fallback function".to_owned(),
+    }
+}
+
 pub struct BindingBuilder<C: Context> {
     context: C,
-    builders: Vec<Box<dyn Fn(&mut C) -> anyhow::Result<()>>>,
+    bindings: Vec<Binding>,
+    /// Error of builder method calls, returned by [`validate`](BindingBuilder::validate) or [`finalize`](BindingBuilder::finalize)
+    error: Option<BindError>,
 }
 
 impl<C: Context + Default> Default for BindingBuilder<C> {
     fn default() -> Self {
         Self {
             context: Default::default(),
-            builders: Default::default(),
+            bindings: Default::default(),
+            error: None,
         }
     }
 }
@@ -397,111 +895,303 @@ impl<C: Context> BindingBuilder<C> {
     pub fn new<C1: Into<C>>(context: C1) -> Self {
         Self {
             context: context.into(),
-            builders: Default::default(),
+            bindings: Default::default(),
+            error: None,
         }
     }
 
+    /// Register binding step, `load` returns contract name and contract data.
+    fn push_builder<F>(mut self, options: ContractOptions, filter: AbiFilter, load: F) -> Self
+    where
+        F: Fn() -> anyhow::Result<(String, Artifact)> + 'static,
+    {
+        self.bindings.push(Binding {
+            load: Box::new(load),
+            options,
+            filter,
+        });
+
+        self
+    }
+
     /// Generate binding codes with contract/abi data
     pub fn bind<S: AsRef<str> + 'static, CN: AsRef<str>>(
-        mut self,
+        self,
         contract_name: CN,
         contract: S,
     ) -> Self {
         let contract_name = contract_name.as_ref().to_string();
 
-        self.builders.push(Box::new(move |c| {
-            let fields: Vec<AbiField> = serde_json::from_str(contract.as_ref())?;
+        self.push_builder(Default::default(), Default::default(), move || {
+            Ok((
+                contract_name.clone(),
+                Artifact::parse(ArtifactFormat::Abi, contract.as_ref())?,
+            ))
+        })
+    }
 
-            {
-                let (generator, runtime_binder) = c.get_mut();
+    /// Generate binding codes with hardhat artifact data
+    pub fn bind_hardhat<S: AsRef<str> + 'static>(self, contract: S) -> Self {
+        self.push_builder(Default::default(), Default::default(), move || {
+            let artifact = Artifact::parse(ArtifactFormat::Hardhat, contract.as_ref())?;
+
+            Ok((
+                artifact.contract_name().unwrap_or_default().to_owned(),
+                artifact,
+            ))
+        })
+    }
 
-                generator.begin(runtime_binder, &contract_name)?;
-            }
+    /// Generate binding codes with contract/abi file path
+    pub fn bind_file<P: AsRef<Path> + 'static, CN: AsRef<str>>(
+        self,
+        contract_name: CN,
+        path: P,
+    ) -> Self {
+        let contract_name = contract_name.as_ref().to_string();
 
-            fields.generate(c)?;
+        self.push_builder(Default::default(), Default::default(), move || {
+            let contract = read_to_string(&path)?;
 
-            Ok(())
-        }));
+            Ok((
+                contract_name.clone(),
+                Artifact::parse(ArtifactFormat::Abi, &contract)?,
+            ))
+        })
+    }
 
-        self
+    /// Generate binding codes with hardhat artifact file path
+    pub fn bind_hardhat_file<P: AsRef<Path> + 'static>(self, path: P) -> Self {
+        self.push_builder(Default::default(), Default::default(), move || {
+            let contract = read_to_string(&path)?;
+
+            let artifact = Artifact::parse(ArtifactFormat::Hardhat, &contract)?;
+
+            Ok((
+                artifact.contract_name().unwrap_or_default().to_owned(),
+                artifact,
+            ))
+        })
     }
 
-    /// Generate binding codes with hardhat artifact data
-    pub fn bind_hardhat<S: AsRef<str> + 'static>(mut self, contract: S) -> Self {
-        self.builders.push(Box::new(move |c| {
-            let fields: HardhatArtifact = serde_json::from_str(contract.as_ref())?;
+    /// Generate binding codes with foundry artifact data, the contract name is read from metadata `compilationTarget` field.
+    pub fn bind_foundry<S: AsRef<str> + 'static>(self, contract: S) -> Self {
+        self.push_builder(Default::default(), Default::default(), move || {
+            let artifact = Artifact::parse(ArtifactFormat::Foundry, contract.as_ref())?;
 
-            {
-                let (generator, runtime_binder) = c.get_mut();
+            let contract_name = artifact
+                .contract_name()
+                .ok_or_else(|| anyhow::format_err!("Foundry artifact metadata not found"))?
+                .to_owned();
 
-                generator.begin(runtime_binder, &fields.contract_name)?;
-            }
+            Ok((contract_name, artifact))
+        })
+    }
 
-            fields.generate(c)?;
+    /// Generate binding codes with foundry artifact file path,
+    /// if the artifact doesn't contain metadata the file stem is used as contract name.
+    pub fn bind_foundry_file<P: AsRef<Path> + 'static>(self, path: P) -> Self {
+        self.push_builder(Default::default(), Default::default(), move || {
+            let contract = read_to_string(&path)?;
 
-            Ok(())
-        }));
+            let artifact = Artifact::parse(ArtifactFormat::Foundry, &contract)?;
 
-        self
+            let contract_name = match artifact.contract_name() {
+                Some(contract_name) => contract_name.to_owned(),
+                None => to_contract_name(path.as_ref())?,
+            };
+
+            Ok((contract_name, artifact))
+        })
     }
 
-    /// Generate binding codes with contract/abi file path
-    pub fn bind_file<P: AsRef<Path> + 'static, CN: AsRef<str>>(
-        mut self,
-        contract_name: CN,
-        path: P,
+    /// Generate binding codes with contract [`config`](ContractConfig), relative path is resolved against `base_dir`.
+    ///
+    /// The functions listed in [`skip`](ContractConfig::skip) are merged into the functions deny list of [`filter`](ContractConfig::filter).
+    pub fn bind_config<P: AsRef<Path>>(self, base_dir: P, config: ContractConfig) -> Self {
+        let path = base_dir.as_ref().join(&config.path);
+
+        self.push_config(path.clone(), config, move || {
+            read_to_string(&path)
+                .map_err(|err| anyhow::format_err!("Read {}: {}", path.display(), err))
+        })
+    }
+
+    /// Generate binding codes with contract `data` and [`config`](ContractConfig), e.g: inline artifact embedded in source codes.
+    ///
+    /// [`path`](ContractConfig::path) isn't read, raw abi requires [`rename`](ContractConfig::rename) unless the path has file stem.
+    pub fn bind_config_data<S: AsRef<str> + 'static>(
+        self,
+        data: S,
+        config: ContractConfig,
     ) -> Self {
-        let contract_name = contract_name.as_ref().to_string();
+        let path = config.path.clone();
 
-        self.builders.push(Box::new(move |c| {
-            let contract = read_to_string(&path)?;
+        self.push_config(path, config, move || Ok(data.as_ref().to_owned()))
+    }
 
-            let fields: Vec<AbiField> = serde_json::from_str(contract.as_ref())?;
+    fn push_config<F>(self, path: PathBuf, config: ContractConfig, read: F) -> Self
+    where
+        F: Fn() -> anyhow::Result<String> + 'static,
+    {
+        let mut filter = config.filter.clone();
 
-            {
-                let (generator, runtime_binder) = c.get_mut();
+        filter.functions.deny.extend(config.skip.iter().cloned());
 
-                generator.begin(runtime_binder, &contract_name)?;
-            }
+        self.push_builder(config.options.clone(), filter, move || {
+            let data = read()?;
 
-            fields.generate(c)?;
+            let format = match config.format {
+                Some(format) => format,
+                None => ArtifactFormat::detect(&data)?,
+            };
 
-            Ok(())
-        }));
+            let artifact = Artifact::parse(format, &data)?;
+
+            let contract_name = match (&config.rename, artifact.contract_name()) {
+                (Some(rename), _) => rename.clone(),
+                (None, Some(contract_name)) => contract_name.to_owned(),
+                (None, None) => to_contract_name(&path)?,
+            };
+
+            Ok((contract_name, artifact))
+        })
+    }
+
+    /// Set [`AbiFilter`] of the last bound contract, e.g:
+    ///
+    /// ```
+    /// # use ethbind_gen::{AbiFilter, BindingBuilder, Category, Context};
+    /// fn bind_lock<C: Context>(builder: BindingBuilder<C>, artifact: &'static str) -> BindingBuilder<C> {
+    ///     builder
+    ///         .bind_hardhat(artifact)
+    ///         .filter(AbiFilter::default().skip(Category::Deploy))
+    /// }
+    /// ```
+    ///
+    /// If no contract is bound, [`BindError::NotBound`] is returned by [`finalize`](BindingBuilder::finalize).
+    pub fn filter(mut self, filter: AbiFilter) -> Self {
+        match self.bindings.last_mut() {
+            Some(binding) => binding.filter = filter,
+            None => {
+                self.error
+                    .get_or_insert(BindError::NotBound("filter".to_owned()));
+            }
+        }
 
         self
     }
 
-    /// Generate binding codes with hardhat artifact file path
-    pub fn bind_hardhat_file<P: AsRef<Path> + 'static>(mut self, path: P) -> Self {
-        self.builders.push(Box::new(move |c| {
-            let contract = read_to_string(&path)?;
+    /// Load and filter all bound contracts.
+    fn load(&self) -> anyhow::Result<Vec<(String, Artifact)>> {
+        if let Some(err) = &self.error {
+            return Err(err.clone().into());
+        }
 
-            let fields: HardhatArtifact = serde_json::from_str(contract.as_ref())?;
+        let mut contracts = vec![];
 
-            {
-                let (generator, runtime_binder) = c.get_mut();
+        for binding in &self.bindings {
+            let (contract_name, mut artifact) = (binding.load)()?;
 
-                generator.begin(runtime_binder, &fields.contract_name)?;
-            }
+            binding
+                .filter
+                .apply(artifact.abi_mut())
+                .map_err(|err| anyhow::format_err!("Filter {}: {}", contract_name, err))?;
+
+            contracts.push((contract_name, artifact));
+        }
 
-            fields.generate(c)?;
+        Ok(contracts)
+    }
 
-            Ok(())
-        }));
+    /// Check the runtime binder covers every type used by `contracts`, returns [`BindError::MissingKeys`]
+    /// which lists all missing keys and the abi types requiring them, with the first parameter using each type.
+    fn check(&mut self, contracts: &[(String, Artifact)]) -> anyhow::Result<()> {
+        let (generator, runtime_binder) = self.context.get_mut();
 
-        self
+        if !generator.uses_runtime_types() {
+            return Ok(());
+        }
+
+        let mut missing: BTreeMap<String, BTreeMap<String, String>> = Default::default();
+
+        for (contract_name, artifact) in contracts {
+            for_each_param(artifact.abi(), &mut |param, location| {
+                for key in runtime_binder.missing_keys(&param.r#type) {
+                    missing
+                        .entry(key)
+                        .or_default()
+                        .entry(param.r#type.to_string())
+                        .or_insert_with(|| format!("{}.{}", contract_name, location()));
+                }
+            });
+        }
+
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        let missing = missing
+            .into_iter()
+            .map(|(key, types)| {
+                let types = types
+                    .into_iter()
+                    .map(|(r#type, location)| format!("{} ({})", r#type, location))
+                    .collect::<Vec<_>>();
+
+                format!("`{}` required by {}", key, types.join(", "))
+            })
+            .collect::<Vec<_>>();
+
+        Err(BindError::MissingKeys(missing.join("; ")).into())
+    }
+
+    /// Load all bound contracts and check the runtime binder covers every type used by them, without generation.
+    pub fn validate(&mut self) -> anyhow::Result<()> {
+        let contracts = self.load()?;
+
+        self.check(&contracts)
     }
 
     /// Retrieve [`result`](Generator) and consume binding builder instance.
+    ///
+    /// The bound contracts are [`validated`](BindingBuilder::validate) before generation starts.
     pub fn finalize(mut self) -> anyhow::Result<Vec<Contract>> {
-        for builder in self.builders {
-            builder(&mut self.context)?;
+        let contracts = self.load()?;
+
+        self.check(&contracts)?;
+
+        for (binding, (contract_name, artifact)) in self.bindings.iter().zip(contracts) {
+            {
+                let (generator, runtime_binder) = self.context.get_mut();
+
+                generator.begin(runtime_binder, &contract_name)?;
+
+                generator.generate_options(runtime_binder, &binding.options)?;
+            }
+
+            artifact
+                .generate(&mut self.context)
+                .with_context(|| format!("Generate contract `{}`", contract_name))?;
         }
 
         let (generator, mut runtime_binder) = self.context.finalize();
 
-        Ok(generator.finalize(&mut runtime_binder)?)
+        generator.finalize(&mut runtime_binder)
+    }
+}
+
+impl<L: Generator> BindingBuilder<Executor<L, JsonRuntimeBinder>> {
+    /// Create binding builder from [`config`](Config) with providing `generator`,
+    /// which binds all declared contracts with declared runtime types mapping.
+    pub fn from_config(generator: L, config: &Config) -> anyhow::Result<Self> {
+        let mut builder = Self::new((generator, config.runtime_binder()?));
+
+        for contract in &config.contracts {
+            builder = builder.bind_config(&config.base_dir, contract.clone());
+        }
+
+        Ok(builder)
     }
 }
 
@@ -510,14 +1200,13 @@ impl Generatable for HardhatArtifact {
         self.abi.generate(context)?;
 
         for abi in &self.abi {
-            match abi {
-                AbiField::Constructor(contructor) => {
-                    // Generate deploy fn
-                    let (generator, runtime_binder) = context.get_mut();
+            if let AbiField::Constructor(contructor) = abi {
+                // Generate deploy fn
+                let (generator, runtime_binder) = context.get_mut();
 
-                    generator.generate_deploy(runtime_binder, contructor, &self.bytecode)?;
-                }
-                _ => {}
+                generator
+                    .generate_deploy(runtime_binder, contructor, &self.bytecode)
+                    .with_context(|| format!("Generate {}", to_item_desc(abi)))?;
             }
         }
 
@@ -525,21 +1214,52 @@ impl Generatable for HardhatArtifact {
     }
 }
 
-impl Generatable for Vec<AbiField> {
+impl Generatable for FoundryArtifact {
+    fn generate<C: Context>(&self, context: &mut C) -> anyhow::Result<()> {
+        {
+            let (generator, runtime_binder) = context.get_mut();
+
+            generator.generate_natspec(runtime_binder, &self.natspec())?;
+        }
+
+        self.abi.generate(context)?;
+
+        for abi in &self.abi {
+            if let AbiField::Constructor(contructor) = abi {
+                // Generate deploy fn
+                let (generator, runtime_binder) = context.get_mut();
+
+                generator
+                    .generate_deploy(runtime_binder, contructor, &self.bytecode.object)
+                    .with_context(|| format!("Generate {}", to_item_desc(abi)))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Generatable for [AbiField] {
     fn generate<C: Context>(&self, context: &mut C) -> anyhow::Result<()> {
         let (generator, runtime_binder) = context.get_mut();
 
         for abi in self {
-            match abi {
-                AbiField::Function(function) => generator.generate_fn(runtime_binder, function)?,
-                AbiField::Event(event) => generator.generate_event(runtime_binder, event)?,
-                AbiField::Error(error) => generator.generate_error(runtime_binder, error)?,
-                _ => {
-                    // Skip generate codes for constructor/receive/fallback.
+            let result = match abi {
+                AbiField::Function(function) => generator.generate_fn(runtime_binder, function),
+                AbiField::Event(event) => generator.generate_event(runtime_binder, event),
+                AbiField::Error(error) => generator.generate_error(runtime_binder, error),
+                AbiField::Receive(receive) => generator.generate_receive(runtime_binder, receive),
+                AbiField::Fallback(fallback) => {
+                    generator.generate_fallback(runtime_binder, fallback)
+                }
+                AbiField::Constructor(_) => {
+                    // Skip generate codes for constructor.
                     // - Call `Generator::generate_deploy` for [`HardhatArtifact`]'s trait `Generate` to generate the constructor's binding code.
-                    // - It seems that end users do not directly call receive/fallback api, so we skip their generation step.
+                    Ok(())
                 }
-            }
+            };
+
+            result.with_context(|| format!("Generate {}", to_item_desc(abi)))?;
         }
 
         Ok(())
@@ -548,9 +1268,32 @@ impl Generatable for Vec<AbiField> {
 
 #[cfg(test)]
 mod tests {
-    use ethbind_json::Type;
+    use ethbind_json::{AbiField, FoundryArtifact, Type};
+
+    use std::fs;
 
-    use crate::{JsonRuntimeBinder, RuntimeBinder};
+    use crate::{
+        is_mapping_key, AbiFilter, Artifact, ArtifactFormat, Category, Config, Contract, File,
+        FnRuntimeBinder, ItemFilter, JsonRuntimeBinder, RuntimeBinder, SaveTo,
+    };
+
+    #[test]
+    fn test_is_mapping_key() {
+        for key in [
+            "address",
+            "uint_m",
+            "array_m",
+            "rt_client",
+            "uint256",
+            "int8",
+        ] {
+            assert!(is_mapping_key(key), "{}", key);
+        }
+
+        for key in ["helper_t", "uint", "uint7", "bytes32", "preset", "Address"] {
+            assert!(!is_mapping_key(key), "{}", key);
+        }
+    }
 
     #[test]
     fn test_json_runtime_binder() {
@@ -612,5 +1355,336 @@ mod tests {
             .expect("Is not tuple type");
 
         assert_eq!(runtime_type, "mock::Address");
+
+        let t: Type = "ufixed".parse().expect("Parse type string");
+
+        let runtime_type = runtime_binder
+            .to_runtime_type(&t)
+            .expect("Get runtime type")
+            .expect("Is not tuple type");
+
+        assert_eq!(runtime_type, "mock::Fixed<false,128,18>");
+    }
+
+    #[test]
+    fn test_integer_width_rules() {
+        let mut runtime_binder: JsonRuntimeBinder = r#"{
+            "uint8..=64": "u$m",
+            "uint72..=128": "u128",
+            "uint136..": "U256",
+            "uint160": "Address",
+            "int8..=64": "i$m",
+            "int_m": "I<$m>"
+        }"#
+        .parse()
+        .expect("Load mapping");
+
+        let mut to_runtime_type = |t: &str| {
+            runtime_binder
+                .to_runtime_type(&t.parse().expect("Parse type"))
+                .expect("Get runtime type")
+                .expect("Is not tuple type")
+                .to_owned()
+        };
+
+        assert_eq!(to_runtime_type("uint8"), "u8");
+        assert_eq!(to_runtime_type("uint64"), "u64");
+        assert_eq!(to_runtime_type("uint96"), "u128");
+        assert_eq!(to_runtime_type("uint160"), "Address");
+        assert_eq!(to_runtime_type("uint"), "U256");
+        assert_eq!(to_runtime_type("int32"), "i32");
+        assert_eq!(to_runtime_type("int256"), "I<256>");
+
+        assert!(runtime_binder
+            .missing_keys(&"uint200".parse().expect("Parse type"))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_fn_runtime_binder() {
+        let mut runtime_binder = FnRuntimeBinder::default()
+            .simple(|c| format!("sol::{}", c))
+            .integer_m(|c| {
+                if c.m <= 64 {
+                    format!("{}{}", if c.signed { "i" } else { "u" }, c.m)
+                } else {
+                    "U256".to_owned()
+                }
+            })
+            .array_m(|el, m| format!("[{};{}]", el, m))
+            .tuple(|types| (types.len() == 2).then_some("Pair"))
+            .key("rt_client", || "Client")
+            .key("address", || "KeyAddress");
+
+        let mut to_runtime_type = |t: &str| {
+            runtime_binder
+                .to_runtime_type(&t.parse().expect("Parse type"))
+                .map(|c| c.map(|c| c.to_owned()))
+        };
+
+        assert_eq!(to_runtime_type("uint8").unwrap().unwrap(), "u8");
+        assert_eq!(to_runtime_type("int").unwrap().unwrap(), "U256");
+        assert_eq!(to_runtime_type("address").unwrap().unwrap(), "sol::address");
+        assert_eq!(to_runtime_type("int32[10]").unwrap().unwrap(), "[i32;10]");
+        assert_eq!(to_runtime_type("tuple[10]").unwrap(), None);
+        assert_eq!(to_runtime_type("(uint8,bool)").unwrap().unwrap(), "Pair");
+        assert_eq!(to_runtime_type("(uint8)").unwrap(), None);
+        assert!(to_runtime_type("bytes32").is_err());
+
+        assert_eq!(runtime_binder.get("rt_client").unwrap(), "Client");
+        assert_eq!(runtime_binder.get("address").unwrap(), "KeyAddress");
+        assert_eq!(runtime_binder.get("bool").unwrap(), "sol::bool");
+        assert!(runtime_binder.get("rt_error").is_err());
+
+        assert_eq!(
+            runtime_binder.missing_keys(&"bytes32[]".parse().expect("Parse type")),
+            vec!["array", "bytes_m"]
+        );
+    }
+
+    #[test]
+    fn test_preset() {
+        assert_eq!(
+            JsonRuntimeBinder::presets().collect::<Vec<_>>(),
+            vec!["ethers-v2", "alloy-v1"]
+        );
+
+        for name in JsonRuntimeBinder::presets() {
+            let runtime_binder = JsonRuntimeBinder::preset(name).expect("Load preset");
+
+            for t in [
+                "address", "bool", "string", "bytes", "bytes32", "uint8", "int256",
+            ] {
+                assert!(
+                    runtime_binder
+                        .missing_keys(&t.parse().expect("Parse type"))
+                        .is_empty(),
+                    "{} {}",
+                    name,
+                    t
+                );
+            }
+        }
+
+        let mut runtime_binder: JsonRuntimeBinder =
+            r#"{ "preset": "ethers-v2", "rt_client": "my_rt::Client" }"#
+                .parse()
+                .expect("Extend preset");
+
+        assert_eq!(runtime_binder.get("rt_client").unwrap(), "my_rt::Client");
+        assert_eq!(runtime_binder.get("rt_error").unwrap(), "anyhow::Error");
+
+        assert_eq!(
+            runtime_binder
+                .to_runtime_type(&"uint24".parse().expect("Parse type"))
+                .unwrap(),
+            Some("ethers::types::U256")
+        );
+
+        assert_eq!(
+            runtime_binder
+                .to_runtime_type(&"int96".parse().expect("Parse type"))
+                .unwrap(),
+            Some("i128")
+        );
+
+        assert!(JsonRuntimeBinder::preset("ethers-v1").is_err());
+        assert!(r#"{ "preset": "ethers-v1" }"#.parse::<JsonRuntimeBinder>().is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let abi = r#"[
+            { "type": "event", "name": "Transfer", "inputs": [], "anonymous": false },
+            {
+                "type": "function",
+                "name": "register",
+                "inputs": [{ "name": "", "type": "uint7" }],
+                "outputs": [],
+                "stateMutability": "nonpayable"
+            }
+        ]"#;
+
+        let err = Artifact::parse(ArtifactFormat::Abi, abi).expect_err("Invalid type");
+
+        assert!(err
+            .to_string()
+            .starts_with("Parse function `register` parameter #0: "));
+
+        let err = Artifact::parse(ArtifactFormat::Abi, "{").expect_err("Invalid json");
+
+        assert!(!err.to_string().starts_with("Parse"));
+    }
+
+    #[test]
+    fn test_missing_keys() {
+        let runtime_binder: JsonRuntimeBinder = r#"{ "array": "Vec<$el>", "uint_m": "u$m" }"#
+            .parse()
+            .expect("Load mapping");
+
+        let missing_keys = |t: &str| runtime_binder.missing_keys(&t.parse().expect("Parse type"));
+
+        assert!(missing_keys("uint8[]").is_empty());
+        assert!(missing_keys("tuple[]").is_empty());
+        assert_eq!(missing_keys("string[10][]"), vec!["array_m", "string"]);
+        assert_eq!(missing_keys("ufixed64x10"), vec!["ufixed_m_n"]);
+        assert_eq!(missing_keys("int"), vec!["int_m"]);
+        assert_eq!(missing_keys("function"), vec!["function"]);
+
+        let runtime_binder: JsonRuntimeBinder = r#"{ "uint256": "U256", "int8..": "I256" }"#
+            .parse()
+            .expect("Load mapping");
+
+        let missing_keys = |t: &str| runtime_binder.missing_keys(&t.parse().expect("Parse type"));
+
+        assert!(missing_keys("uint").is_empty());
+        assert!(missing_keys("int").is_empty());
+        assert_eq!(missing_keys("uint8"), vec!["uint_m"]);
+    }
+
+    #[test]
+    fn test_invalid_width_range() {
+        for key in [
+            "uint8..64",
+            "int..=64",
+            "uint64..=8",
+            "uintx..",
+            "uint8..=300",
+            "uint7..=9",
+            "int0..",
+            "uint264..",
+        ] {
+            let mapping = format!(r#"{{ "{}": "u$m" }}"#, key);
+
+            assert!(
+                mapping.parse::<JsonRuntimeBinder>().is_err(),
+                "{} should be rejected",
+                key
+            );
+        }
+
+        assert!(
+            r#"{ "uint8..=64": "u$m", "int136..": "I256", "uint256..=256": "U256" }"#
+                .parse::<JsonRuntimeBinder>()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_clean_save_to() {
+        let output_dir = std::env::temp_dir().join("ethbind_test_clean_save_to");
+
+        _ = fs::remove_dir_all(&output_dir);
+
+        fs::create_dir_all(&output_dir).expect("Create output dir");
+
+        fs::write(output_dir.join("stale.rs"), "").expect("Write stale file");
+        fs::write(output_dir.join("README.md"), "").expect("Write user file");
+
+        let contracts = vec![Contract {
+            files: vec![File {
+                name: "lock.rs".to_owned(),
+                data: "pub struct Lock;".to_owned(),
+            }],
+        }];
+
+        contracts
+            .clean_save_to(&output_dir)
+            .expect("Save generated files");
+
+        assert!(output_dir.join("lock.rs").exists());
+        assert!(output_dir.join("README.md").exists());
+        assert!(!output_dir.join("stale.rs").exists());
+
+        fs::remove_dir_all(&output_dir).expect("Remove output dir");
+    }
+
+    #[test]
+    fn test_config() {
+        let config: Config = r#"
+            [mapping]
+            address = "Address"
+
+            [output]
+            dir = "src/sol"
+            language = "go"
+            package = "sol"
+            client_trait = true
+            call_builder = "false"
+            pretty = 1
+
+            [[contracts]]
+            path = "Lock.json"
+            format = "hardhat"
+            skip = ["withdraw"]
+            derives = ["Debug"]
+            "#
+        .parse()
+        .expect("Parse config");
+
+        let mut runtime_binder = config.runtime_binder().expect("Load mapping");
+
+        assert_eq!(
+            runtime_binder.get("address").expect("Get address"),
+            "Address"
+        );
+
+        assert_eq!(config.option("package"), Some("sol"));
+        assert_eq!(config.option("client_trait"), Some("true"));
+        assert_eq!(config.flag("client_trait").unwrap(), Some(true));
+        assert_eq!(config.flag("call_builder").unwrap(), Some(false));
+        assert_eq!(config.flag("clean").unwrap(), None);
+        assert!(config.flag("pretty").is_err());
+        assert_eq!(config.contracts[0].format, Some(ArtifactFormat::Hardhat));
+        assert_eq!(config.contracts[0].skip, vec!["withdraw"]);
+        assert_eq!(config.contracts[0].options.derives, vec!["Debug"]);
+    }
+
+    #[test]
+    fn test_abi_filter() {
+        let load = || {
+            serde_json::from_str::<FoundryArtifact>(include_str!(
+                "../../rust/macros/tests/foundry.json"
+            ))
+            .expect("Parse foundry artifact")
+            .abi
+        };
+
+        let mut abi = load();
+
+        AbiFilter::default()
+            .functions(ItemFilter::default().allow("balanceOf").allow("0xA9059CBB"))
+            .events(ItemFilter::default().deny("Transfer(address,address,uint256)"))
+            .skip(Category::Deploy)
+            .apply(&mut abi)
+            .expect("Apply filter");
+
+        let names = abi
+            .iter()
+            .map(|c| match c {
+                AbiField::Function(function) => function.name.as_str(),
+                AbiField::Event(event) => event.name.as_str(),
+                AbiField::Error(error) => error.name.as_str(),
+                _ => "",
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["balanceOf", "transfer", "InsufficientBalance"]);
+
+        let mut abi = load();
+
+        AbiFilter::default()
+            .functions(ItemFilter::default().deny("transfer(address)"))
+            .skip(Category::Errors)
+            .apply(&mut abi)
+            .expect_err("Unknown function");
+
+        AbiFilter::default()
+            .functions(ItemFilter::default().deny("transfer(address,uint256)"))
+            .skip(Category::Errors)
+            .apply(&mut abi)
+            .expect("Deny transfer");
+
+        assert_eq!(abi.len(), 3);
     }
 }
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use crate::{is_width_range_key, parse_width_range, BindError, JsonRuntimeBinder};
+
+/// Bundled runtime types mappings, indexed by versioned name.
+///
+/// The runtime glue keys (`rt_client`, `rt_opts`, `rt_abi_serialize`, `rt_abi_deserialize`)
+/// point to the `crate::ethbind_rt` module, which is supplied by the user or overridden by mapping keys.
+const PRESETS: &[(&str, &str)] = &[
+    ("ethers-v2", include_str!("../presets/ethers-v2.json")),
+    ("alloy-v1", include_str!("../presets/alloy-v1.json")),
+];
+
+impl JsonRuntimeBinder {
+    /// Load bundled runtime types mapping by `name`, e.g: `ethers-v2` or `alloy-v1`.
+    ///
+    /// A mapping json can also extend a preset by the `preset` key, the other keys override the preset ones:
+    ///
+    /// ```json
+    /// { "preset": "ethers-v2", "rt_client": "my_rt::Client" }
+    /// ```
+    pub fn preset(name: &str) -> anyhow::Result<Self> {
+        let (_, data) = PRESETS
+            .iter()
+            .find(|(preset, _)| *preset == name)
+            .ok_or_else(|| BindError::UnknownPreset(name.to_owned()))?;
+
+        Ok(Self {
+            runtime_types: serde_json::from_str(data)?,
+            components: Default::default(),
+        })
+    }
+
+    /// Returns names of bundled presets.
+    pub fn presets() -> impl Iterator<Item = &'static str> {
+        PRESETS.iter().map(|(name, _)| *name)
+    }
+}
+
+impl TryFrom<HashMap<String, String>> for JsonRuntimeBinder {
+    type Error = anyhow::Error;
+
+    fn try_from(mut runtime_types: HashMap<String, String>) -> Result<Self, Self::Error> {
+        let mut binder = match runtime_types.remove("preset") {
+            Some(name) => Self::preset(&name)?,
+            None => Self::default(),
+        };
+
+        for key in runtime_types.keys().filter(|key| is_width_range_key(key)) {
+            let range = key.strip_prefix('u').unwrap_or(key).strip_prefix("int");
+
+            if range.and_then(parse_width_range).is_none() {
+                return Err(BindError::InvalidWidthRange(key.clone()).into());
+            }
+        }
+
+        binder.runtime_types.extend(runtime_types);
+
+        Ok(binder)
+    }
+}
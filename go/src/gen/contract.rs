@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use ethbind_gen::{Contract, File};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+
+#[derive(Debug, Default)]
+pub(crate) struct ContractGenerator {
+    pub(crate) contract_name: String,
+    struct_names: Vec<String>,
+    struct_codes: Vec<String>,
+    /// Declared struct names, keyed by `internalType` struct path or anonymous tuple components
+    struct_keys: HashMap<String, String>,
+    fn_codes: Vec<String>,
+    event_codes: Vec<String>,
+    error_codes: Vec<String>,
+    /// Generated method names counter, used to rename overloaded functions
+    method_names: HashMap<String, usize>,
+}
+
+impl ContractGenerator {
+    pub(crate) fn new(contract_name: &str) -> Self {
+        Self {
+            contract_name: contract_name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// Contract go type name
+    pub(crate) fn go_name(&self) -> String {
+        self.contract_name.to_upper_camel_case()
+    }
+
+    /// Returns unique method name, overloaded methods will be suffixed by index like `abigen` does, e.g: `Mint`,`Mint0`,`Mint1`
+    pub(crate) fn method_name(&mut self, name: &str) -> String {
+        let counter = self.method_names.entry(name.to_owned()).or_insert(0);
+
+        *counter += 1;
+
+        if *counter == 1 {
+            name.to_owned()
+        } else {
+            format!("{}{}", name, *counter - 2)
+        }
+    }
+
+    /// Returns struct name for anonymous tuple
+    pub(crate) fn next_tuple_name(&self) -> String {
+        format!("{}Tuple{}", self.go_name(), self.struct_names.len())
+    }
+
+    pub(crate) fn has_struct(&self, name: &str) -> bool {
+        self.struct_names.iter().any(|n| n == name)
+    }
+
+    /// Returns struct name declared for `key`
+    pub(crate) fn declared_struct(&self, key: &str) -> Option<&str> {
+        self.struct_keys.get(key).map(|c| c.as_str())
+    }
+
+    /// Reserve struct `name` for `key` before generating its fields, so nested tuples can't take the same name.
+    /// Returns the index to [`set_struct_code`](Self::set_struct_code).
+    pub(crate) fn reserve_struct(&mut self, key: &str, name: &str) -> usize {
+        self.struct_keys.insert(key.to_owned(), name.to_owned());
+        self.struct_names.push(name.to_owned());
+        self.struct_codes.push(String::new());
+
+        self.struct_codes.len() - 1
+    }
+
+    pub(crate) fn set_struct_code(&mut self, index: usize, code: String) {
+        self.struct_codes[index] = code;
+    }
+
+    pub(crate) fn add_fn(&mut self, code: String) {
+        self.fn_codes.push(code);
+    }
+
+    pub(crate) fn add_event(&mut self, code: String) {
+        self.event_codes.push(code);
+    }
+
+    pub(crate) fn add_error(&mut self, code: String) {
+        self.error_codes.push(code);
+    }
+
+    pub(crate) fn finalize(
+        &self,
+        package: &str,
+        rt_client: &str,
+        rt_address: &str,
+        rt_imports: &[String],
+    ) -> anyhow::Result<Contract> {
+        let ident = self.go_name();
+
+        let mut body = format!(
+            r#"// {ident} is an auto generated Go binding around an Ethereum contract.
+type {ident} struct {{
+	Client  {rt_client}
+	Address {rt_address}
+}}
+
+// New{ident} creates a new instance of {ident}, bound to a specific deployed contract.
+func New{ident}(client {rt_client}, address {rt_address}) *{ident} {{
+	return &{ident}{{Client: client, Address: address}}
+}}
+"#
+        );
+
+        for code in self
+            .struct_codes
+            .iter()
+            .chain(self.fn_codes.iter())
+            .chain(self.event_codes.iter())
+            .chain(self.error_codes.iter())
+        {
+            body.push('\n');
+            body.push_str(code);
+        }
+
+        // Only import packages referenced by generated codes, go compiler rejects unused imports.
+        let mut imports = rt_imports
+            .iter()
+            .filter(|path| {
+                let name = path.rsplit('/').next().unwrap_or(path);
+
+                body.contains(&format!("{}.", name))
+            })
+            .map(|path| format!("\t\"{}\"\n", path))
+            .collect::<Vec<_>>();
+
+        imports.sort();
+
+        let imports = if imports.is_empty() {
+            String::new()
+        } else {
+            format!("import (\n{})\n\n", imports.join(""))
+        };
+
+        let data = format!(
+            "// Code generated by ethbind. DO NOT EDIT.\n\npackage {}\n\n{}{}",
+            package, imports, body
+        );
+
+        Ok(Contract {
+            files: vec![File {
+                name: format!("{}.go", self.contract_name.to_snake_case()),
+                data,
+            }],
+        })
+    }
+}
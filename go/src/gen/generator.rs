@@ -0,0 +1,347 @@
+use ethbind_gen::Generator;
+use ethbind_json::*;
+
+use crate::GoGenerator;
+
+use super::{to_exported_name, to_struct_fields, to_var_name};
+
+/// Local variable names used by generated method bodies.
+const RESERVED_VARS: &[&str] = &[
+    "c", "client", "opts", "data", "result", "receipt", "address", "err",
+];
+
+/// Convert `params` to unique go variable names, `prefix` is used for unnamed parameters.
+fn to_var_names(params: &[Parameter], prefix: &str, used: &mut Vec<String>) -> Vec<String> {
+    let mut names = vec![];
+
+    for (index, param) in params.iter().enumerate() {
+        let mut name = to_var_name(&param.name, format!("{}{}", prefix, index));
+
+        while RESERVED_VARS.contains(&name.as_str()) || used.contains(&name) {
+            name.push('_');
+        }
+
+        used.push(name.clone());
+        names.push(name);
+    }
+
+    names
+}
+
+/// Generate go fn param list, e.g: `to common.Address, value *big.Int`
+fn to_param_list(names: &[String], types: &[String]) -> String {
+    names
+        .iter()
+        .zip(types)
+        .map(|(name, r#type)| format!("{} {}", name, r#type))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Generate go struct fields list for event/error parameters.
+fn to_fields(params: &[Parameter], types: Vec<String>) -> Vec<(String, String, String)> {
+    params
+        .iter()
+        .zip(types)
+        .enumerate()
+        .map(|(index, (param, r#type))| {
+            (
+                to_exported_name(&param.name, format!("Arg{}", index)),
+                r#type,
+                param.name.clone(),
+            )
+        })
+        .collect()
+}
+
+impl Generator for GoGenerator {
+    fn begin<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        name: &str,
+    ) -> anyhow::Result<()> {
+        self.new_contract(name);
+
+        Ok(())
+    }
+
+    fn end<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        _name: &str,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn finalize<R: ethbind_gen::RuntimeBinder>(
+        self,
+        runtime_binder: &mut R,
+    ) -> anyhow::Result<Vec<ethbind_gen::Contract>> {
+        let client_type = self.to_runtime_type(runtime_binder, "rt_client")?;
+        let address = self.to_runtime_type(runtime_binder, "address")?;
+
+        // `rt_imports` is optional, runtime types may all be declared in generated package.
+        let imports = runtime_binder
+            .get("rt_imports")
+            .map(|imports| {
+                imports
+                    .split(',')
+                    .map(|path| path.trim().to_owned())
+                    .filter(|path| !path.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let mut contracts = vec![];
+
+        for c in &self.contracts {
+            contracts.push(c.finalize(&self.package, &client_type, &address, &imports)?);
+        }
+
+        Ok(contracts)
+    }
+
+    fn generate_deploy<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        runtime_binder: &mut R,
+        contructor: &Constructor,
+        deploy_bytes: &str,
+    ) -> anyhow::Result<()> {
+        let client_type = self.to_runtime_type(runtime_binder, "rt_client")?;
+
+        let opts_type = self.to_runtime_type(runtime_binder, "rt_opts")?;
+
+        let abi_encode = self.to_runtime_type(runtime_binder, "rt_abi_serialize")?;
+
+        let types = self.to_go_types(runtime_binder, &contructor.inputs)?;
+
+        let names = to_var_names(&contructor.inputs, "arg", &mut vec![]);
+
+        let param_list = to_param_list(&names, &types);
+
+        let args = names.join(", ");
+
+        let (param_list, args) = if names.is_empty() {
+            (String::new(), String::new())
+        } else {
+            (format!(", {}", param_list), format!(", {}", args))
+        };
+
+        let ident = self.current_contract().go_name();
+
+        let fn_signature = contructor.signature();
+
+        self.current_contract().add_fn(format!(
+            r#"// {ident}Bytecode is the bytecode used for deploying new contracts.
+const {ident}Bytecode = "{deploy_bytes}"
+
+// Deploy{ident} deploys a new Ethereum contract, binding an instance of {ident} to it.
+func Deploy{ident}(client {client_type}{param_list}) (*{ident}, error) {{
+	var opts {opts_type}
+	return Deploy{ident}With(client{args}, opts)
+}}
+
+// Deploy{ident}With deploys a new Ethereum contract with transaction options.
+func Deploy{ident}With(client {client_type}{param_list}, opts {opts_type}) (*{ident}, error) {{
+	data, err := {abi_encode}({encode_args})
+	if err != nil {{
+		return nil, err
+	}}
+	address, err := client.DeployContract("{fn_signature}", data, {ident}Bytecode, opts)
+	if err != nil {{
+		return nil, err
+	}}
+	return New{ident}(client, address), nil
+}}
+"#,
+            encode_args = names.join(", ")
+        ));
+
+        Ok(())
+    }
+
+    fn generate_error<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        runtime_binder: &mut R,
+        error: &Error,
+    ) -> anyhow::Result<()> {
+        log::trace!("generate error {}", error.name);
+
+        let types = self.to_go_types(runtime_binder, &error.inputs)?;
+
+        let fields = to_struct_fields(&to_fields(&error.inputs, types));
+
+        let ident = format!(
+            "{}{}Error",
+            self.current_contract().go_name(),
+            to_exported_name(&error.name, String::new())
+        );
+
+        let signature = error.signature();
+
+        let selector = to_hex(error.selector());
+
+        self.current_contract().add_error(format!(
+            r#"// {ident} represents a {name} custom error raised by the contract.
+type {ident} struct {{
+{fields}}}
+
+// {ident}Signature is the canonical signature of the {name} custom error.
+const {ident}Signature = "{signature}"
+
+// {ident}Selector is the first 4 bytes of the keccak256 hash of the error signature.
+const {ident}Selector = "{selector}"
+"#,
+            name = error.name
+        ));
+
+        Ok(())
+    }
+
+    fn generate_event<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        runtime_binder: &mut R,
+        event: &Event,
+    ) -> anyhow::Result<()> {
+        log::trace!("generate event {}", event.name);
+
+        let types = self.to_go_types(runtime_binder, &event.inputs)?;
+
+        let fields = to_struct_fields(&to_fields(&event.inputs, types));
+
+        let ident = format!(
+            "{}{}",
+            self.current_contract().go_name(),
+            to_exported_name(&event.name, String::new())
+        );
+
+        let signature = event.signature();
+
+        let mut code = format!(
+            r#"// {ident} represents a {name} event raised by the contract.
+type {ident} struct {{
+{fields}}}
+
+// {ident}Signature is the canonical signature of the {name} event.
+const {ident}Signature = "{signature}"
+"#,
+            name = event.name
+        );
+
+        // Anonymous events don't emit the signature topic.
+        if !event.anonymous {
+            code.push_str(&format!(
+                r#"
+// {ident}Topic is the keccak256 hash of the event signature, emitted as the first log topic.
+const {ident}Topic = "{topic}"
+"#,
+                topic = to_hex(event.topic())
+            ));
+        }
+
+        self.current_contract().add_event(code);
+
+        Ok(())
+    }
+
+    fn generate_fn<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        runtime_binder: &mut R,
+        function: &Function,
+    ) -> anyhow::Result<()> {
+        log::trace!("genearte fn {}", function.name);
+
+        let opts_type = self.to_runtime_type(runtime_binder, "rt_opts")?;
+
+        let abi_encode = self.to_runtime_type(runtime_binder, "rt_abi_serialize")?;
+
+        let abi_decode = self.to_runtime_type(runtime_binder, "rt_abi_deserialize")?;
+
+        let receipt_type = self.to_runtime_type(runtime_binder, "rt_receipt")?;
+
+        let input_types = self.to_go_types(runtime_binder, &function.inputs)?;
+
+        let output_types = self.to_go_types(runtime_binder, &function.outputs)?;
+
+        let mut used = vec![];
+
+        let input_names = to_var_names(&function.inputs, "arg", &mut used);
+
+        let output_names = to_var_names(&function.outputs, "out", &mut used);
+
+        let param_list = to_param_list(&input_names, &input_types);
+
+        let args = input_names.join(", ");
+
+        let ident = self.current_contract().go_name();
+
+        let method = self
+            .current_contract()
+            .method_name(&to_exported_name(&function.name, String::new()));
+
+        let fn_signature = function.signature();
+
+        let send_transaction = !matches!(
+            function.state_mutability,
+            StateMutability::Pure | StateMutability::View
+        );
+
+        if send_transaction {
+            let (with_param_list, with_args) = if input_names.is_empty() {
+                ("opts".to_owned(), "opts".to_owned())
+            } else {
+                (format!("{}, opts", param_list), format!("{}, opts", args))
+            };
+
+            self.current_contract().add_fn(format!(
+                r#"// {method} is a paid mutator transaction binding the contract method `{fn_signature}`.
+func (c *{ident}) {method}({param_list}) ({receipt_type}, error) {{
+	var opts {opts_type}
+	return c.{method}With({with_args})
+}}
+
+// {method}With is a paid mutator transaction binding the contract method `{fn_signature}` with transaction options.
+func (c *{ident}) {method}With({with_param_list} {opts_type}) (receipt {receipt_type}, err error) {{
+	data, err := {abi_encode}({args})
+	if err != nil {{
+		return
+	}}
+	return c.Client.SendRawTransaction("{fn_signature}", c.Address, data, opts)
+}}
+"#
+            ));
+        } else {
+            let output_list = to_param_list(&output_names, &output_types);
+
+            let output_list = if output_list.is_empty() {
+                "err error".to_owned()
+            } else {
+                format!("{}, err error", output_list)
+            };
+
+            let decode_args = output_names
+                .iter()
+                .map(|name| format!(", &{}", name))
+                .collect::<String>();
+
+            self.current_contract().add_fn(format!(
+                r#"// {method} is a free data retrieval call binding the contract method `{fn_signature}`.
+func (c *{ident}) {method}({param_list}) ({output_list}) {{
+	data, err := {abi_encode}({args})
+	if err != nil {{
+		return
+	}}
+	result, err := c.Client.EthCall("{fn_signature}", c.Address, data)
+	if err != nil {{
+		return
+	}}
+	err = {abi_decode}(result{decode_args})
+	return
+}}
+"#
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
+
+/// The golang generator for `Ethbind`
+#[derive(Debug)]
+pub struct GoGenerator {
+    package: String,
+    contracts: Vec<ContractGenerator>,
+}
+
+impl Default for GoGenerator {
+    fn default() -> Self {
+        Self::new("bindings")
+    }
+}
+
+impl GoGenerator {
+    /// Create new generator, the generated files will be declared in go package `package`
+    pub fn new<P: AsRef<str>>(package: P) -> Self {
+        Self {
+            package: package.as_ref().to_owned(),
+            contracts: Default::default(),
+        }
+    }
+
+    /// Push new contract generator to back end of generation list
+    pub(crate) fn new_contract(&mut self, name: &str) {
+        self.contracts.push(ContractGenerator::new(name))
+    }
+
+    /// Returns contract generator at back edn of generation list.
+    pub(crate) fn current_contract(&mut self) -> &mut ContractGenerator {
+        self.contracts.last_mut().expect("Call new_contract first")
+    }
+
+    pub(crate) fn to_runtime_type<R: ethbind_gen::RuntimeBinder>(
+        &self,
+        runtime_binder: &mut R,
+        name: &str,
+    ) -> anyhow::Result<String> {
+        Ok(runtime_binder.get(name)?.to_owned())
+    }
+}
+
+/// Go language reserved keywords, which can't be used as identifiers.
+const KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+/// Convert abi parameter name to go local variable name, returns `default` if the name is empty.
+pub(crate) fn to_var_name(name: &str, default: String) -> String {
+    let name = name.to_lower_camel_case();
+
+    if name.is_empty() {
+        default
+    } else if KEYWORDS.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+/// Convert abi name to go exported identifier, returns `default` if the name is empty.
+pub(crate) fn to_exported_name(name: &str, default: String) -> String {
+    let name = name.to_upper_camel_case();
+
+    if name.is_empty() {
+        default
+    } else {
+        name
+    }
+}
+
+/// Format go struct fields list, align field names/types like `gofmt` does.
+pub(crate) fn to_struct_fields(fields: &[(String, String, String)]) -> String {
+    let name_width = fields.iter().map(|f| f.0.len()).max().unwrap_or(0);
+    let type_width = fields.iter().map(|f| f.1.len()).max().unwrap_or(0);
+
+    fields
+        .iter()
+        .map(|(name, r#type, tag)| {
+            format!(
+                "\t{:name_width$} {:type_width$} `abi:\"{}\"`\n",
+                name, r#type, tag
+            )
+        })
+        .collect()
+}
+
+mod generator;
+
+mod types;
+
+mod contract;
+use contract::*;
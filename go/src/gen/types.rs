@@ -0,0 +1,152 @@
+use ethbind_json::{Parameter, SimpleType, Type};
+
+use crate::GoGenerator;
+
+use super::{to_exported_name, to_struct_fields};
+
+#[allow(clippy::wrong_self_convention)]
+impl GoGenerator {
+    /// Map abi parameter to go type, tuple parameters are mapped to generated struct types.
+    pub(crate) fn to_go_type<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        runtime_binder: &mut R,
+        param: &Parameter,
+    ) -> anyhow::Result<String> {
+        if let Some(runtime_type) = runtime_binder.to_runtime_type(&param.r#type)? {
+            return Ok(runtime_type.to_owned());
+        }
+
+        let struct_name = self.declare_struct(runtime_binder, param)?;
+
+        self.wrap_tuple_type(runtime_binder, &param.r#type, &struct_name)
+    }
+
+    /// Map parameter list to go type list.
+    pub(crate) fn to_go_types<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        runtime_binder: &mut R,
+        params: &[Parameter],
+    ) -> anyhow::Result<Vec<String>> {
+        let mut types = vec![];
+
+        for param in params {
+            types.push(self.to_go_type(runtime_binder, param)?);
+        }
+
+        Ok(types)
+    }
+
+    /// Wrap tuple struct type with array declarations of `r#type`, e.g: `tuple[][2]` => `[2][]Struct`
+    fn wrap_tuple_type<R: ethbind_gen::RuntimeBinder>(
+        &self,
+        runtime_binder: &mut R,
+        r#type: &Type,
+        struct_name: &str,
+    ) -> anyhow::Result<String> {
+        match r#type {
+            Type::Simple(SimpleType::Tuple) | Type::Tuple(_) => Ok(struct_name.to_owned()),
+            Type::Array(array) => {
+                let element = self.wrap_tuple_type(runtime_binder, &array.element, struct_name)?;
+
+                Ok(self
+                    .to_runtime_type(runtime_binder, "array")?
+                    .replace("$el", &element))
+            }
+            Type::ArrayM(array_m) => {
+                let element =
+                    self.wrap_tuple_type(runtime_binder, &array_m.element, struct_name)?;
+
+                Ok(self
+                    .to_runtime_type(runtime_binder, "array_m")?
+                    .replace("$el", &element)
+                    .replace("$m", &array_m.m.to_string()))
+            }
+            _ => Err(anyhow::format_err!(
+                "Expect tuple or array of tuple, got {}",
+                r#type
+            )),
+        }
+    }
+
+    /// Declare go struct for tuple parameter and returns the struct name.
+    ///
+    /// Structs are keyed by `internalType` struct path, or by components of anonymous tuple.
+    /// A struct name already used by another path is qualified by the path, e.g: `A.Order`, `B.Order` => `Order`, `BOrder`
+    fn declare_struct<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        runtime_binder: &mut R,
+        param: &Parameter,
+    ) -> anyhow::Result<String> {
+        let components = param.components.as_ref().ok_or(anyhow::format_err!(
+            "Tuple parameter {}'s components field is None",
+            param.name
+        ))?;
+
+        let key = match param.struct_path() {
+            Some(path) => path.to_owned(),
+            None => serde_json::to_string(components)?,
+        };
+
+        let contract = self.current_contract();
+
+        if let Some(struct_name) = contract.declared_struct(&key) {
+            return Ok(struct_name.to_owned());
+        }
+
+        let mut struct_name = match (param.struct_name(), param.struct_path()) {
+            (Some(name), Some(path)) => {
+                let struct_name = format!(
+                    "{}{}",
+                    contract.go_name(),
+                    to_exported_name(name, String::new())
+                );
+
+                if contract.has_struct(&struct_name) {
+                    format!(
+                        "{}{}",
+                        contract.go_name(),
+                        to_exported_name(&path.replace('.', "_"), String::new())
+                    )
+                } else {
+                    struct_name
+                }
+            }
+            _ => contract.next_tuple_name(),
+        };
+
+        let base_name = struct_name.clone();
+
+        let mut suffix = 0;
+
+        while contract.has_struct(&struct_name) {
+            struct_name = format!("{}{}", base_name, suffix);
+            suffix += 1;
+        }
+
+        let index = contract.reserve_struct(&key, &struct_name);
+
+        let mut fields = vec![];
+
+        for (index, c) in components.iter().enumerate() {
+            let field_type = self.to_go_type(runtime_binder, c)?;
+
+            fields.push((
+                to_exported_name(&c.name, format!("Field{}", index)),
+                field_type,
+                c.name.clone(),
+            ));
+        }
+
+        self.current_contract().set_struct_code(
+            index,
+            format!(
+                "// {} is an auto generated low-level Go binding around an user-defined struct.\ntype {} struct {{\n{}}}\n",
+                struct_name,
+                struct_name,
+                to_struct_fields(&fields)
+            ),
+        );
+
+        Ok(struct_name)
+    }
+}
@@ -0,0 +1,81 @@
+mod gen;
+pub use gen::*;
+
+pub type BindingBuilder =
+    ethbind_gen::BindingBuilder<ethbind_gen::Executor<GoGenerator, ethbind_gen::JsonRuntimeBinder>>;
+
+pub use ethbind_gen::*;
+pub use ethbind_json::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gen() {
+        _ = pretty_env_logger::try_init();
+
+        let runtime_binder: JsonRuntimeBinder = include_str!("../tests/mapping.json")
+            .parse()
+            .expect("Parse mapping");
+
+        let contracts = BindingBuilder::new((GoGenerator::new("wallet"), runtime_binder))
+            .bind_hardhat(include_str!("../../rust/macros/tests/abi.json"))
+            .finalize()
+            .expect("Generate data");
+
+        assert_eq!(contracts.len(), 1);
+
+        let file = &contracts[0].files[0];
+
+        assert_eq!(file.name, "personal_wallet.go");
+
+        assert!(file.data.contains("package wallet"));
+        assert!(file.data.contains("\t\"math/big\"\n"));
+        assert!(file.data.contains("type PersonalWallet struct {"));
+        assert!(file.data.contains("type PersonalWalletMetadata struct {"));
+        assert!(file.data.contains("func DeployPersonalWallet(client *ethrt.Client, weth common.Address) (*PersonalWallet, error) {"));
+        assert!(file.data.contains("func (c *PersonalWallet) MakerMetadata(makerId *big.Int) (maker PersonalWalletMetadata, sentSkuQuantityOrId *big.Int, receivedPaymentQuantityOrId *big.Int, dex common.Address, err error) {"));
+        assert!(file.data.contains("func (c *PersonalWallet) MintMakerWith(maker PersonalWalletMetadata, dex common.Address, opts ethrt.Opts) (receipt *types.Receipt, err error) {"));
+        // keccak256("OwnershipTransferred(address,address)")
+        assert!(file.data.contains("const PersonalWalletOwnershipTransferredTopic = \"0x8be0079c531659141344cd1fd0a4f28419497f9722a3daafe3b4186f6b6457e0\""));
+    }
+
+    #[test]
+    fn test_nested_tuple_structs() {
+        let mut abi: Vec<AbiField> = parse_human_readable(&[
+            "function anonymous((uint256 id, (address owner, bool active) inner) value)",
+            "function again((uint256 id, (address owner, bool active) inner) value)",
+            "function orders((address maker) a, (address taker) b)",
+        ])
+        .expect("Parse abi");
+
+        // Same struct name declared by different parents
+        if let AbiField::Function(function) = &mut abi[2] {
+            function.inputs[0].internal_type = Some("struct A.Order".to_owned());
+            function.inputs[1].internal_type = Some("struct B.Order".to_owned());
+        }
+
+        let runtime_binder: JsonRuntimeBinder = include_str!("../tests/mapping.json")
+            .parse()
+            .expect("Parse mapping");
+
+        let contracts = BindingBuilder::new((GoGenerator::new("nested"), runtime_binder))
+            .bind("Nested", serde_json::to_string(&abi).unwrap())
+            .finalize()
+            .expect("Generate data");
+
+        let data = &contracts[0].files[0].data;
+
+        assert_eq!(data.matches("type NestedTuple0 struct {").count(), 1);
+        assert_eq!(data.matches("type NestedTuple1 struct {").count(), 1);
+        assert!(!data.contains("NestedTuple2"));
+        assert!(data.contains("\tInner NestedTuple1 `abi:\"inner\"`\n"));
+        assert!(
+            data.contains("func (c *Nested) Again(value NestedTuple0) (*types.Receipt, error) {")
+        );
+
+        assert!(data.contains("type NestedOrder struct {\n\tMaker common.Address"));
+        assert!(data.contains("type NestedBOrder struct {\n\tTaker common.Address"));
+    }
+}
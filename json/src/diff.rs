@@ -0,0 +1,408 @@
+//! Compatibility diff between two versions of contract abi, e.g: before and after a proxy upgrade.
+//!
+//! Functions, events and errors are matched by canonical signature, so a changed parameter list is
+//! reported as one item removed and another added.
+
+use std::{collections::HashMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{to_hex, to_selector, AbiField, Event, Function, StateMutability};
+
+/// One abi change, see [`diff_abi`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AbiChange {
+    /// `true` if consumers of the old abi may fail against the new one
+    pub breaking: bool,
+    #[serde(flatten)]
+    pub kind: ChangeKind,
+}
+
+impl From<ChangeKind> for AbiChange {
+    fn from(kind: ChangeKind) -> Self {
+        Self {
+            breaking: kind.is_breaking(),
+            kind,
+        }
+    }
+}
+
+impl Display for AbiChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.breaking {
+            write!(f, "[breaking] {}", self.kind)
+        } else {
+            write!(f, "[non-breaking] {}", self.kind)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "change", rename_all = "camelCase")]
+pub enum ChangeKind {
+    FunctionRemoved {
+        signature: String,
+    },
+    FunctionAdded {
+        signature: String,
+    },
+    /// Canonical output types of the function are changed
+    OutputsChanged {
+        signature: String,
+        old: Vec<String>,
+        new: Vec<String>,
+    },
+    MutabilityChanged {
+        signature: String,
+        old: StateMutability,
+        new: StateMutability,
+    },
+    EventRemoved {
+        signature: String,
+    },
+    EventAdded {
+        signature: String,
+    },
+    /// Positions of the indexed event parameters are changed
+    IndexedChanged {
+        signature: String,
+        old: Vec<usize>,
+        new: Vec<usize>,
+    },
+    AnonymousChanged {
+        signature: String,
+        anonymous: bool,
+    },
+    ErrorRemoved {
+        signature: String,
+    },
+    ErrorAdded {
+        signature: String,
+    },
+    /// Functions with different signatures share one selector, one of them is declared by the new abi
+    SelectorCollision {
+        selector: String,
+        signatures: Vec<String>,
+    },
+}
+
+impl ChangeKind {
+    /// Returns true if consumers of the old abi may fail against the new one.
+    ///
+    /// Added items and removed errors are compatible, mutability changes are breaking if
+    /// a read-only function now requires a transaction or a payable function rejects value.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            Self::FunctionAdded { .. }
+            | Self::EventAdded { .. }
+            | Self::ErrorAdded { .. }
+            | Self::ErrorRemoved { .. } => false,
+            Self::MutabilityChanged { old, new, .. } => matches!(
+                (old, new),
+                (
+                    StateMutability::Pure | StateMutability::View,
+                    StateMutability::Nonpayable | StateMutability::Payable
+                ) | (StateMutability::Payable, _)
+            ),
+            _ => true,
+        }
+    }
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FunctionRemoved { signature } => write!(f, "function `{}` removed", signature),
+            Self::FunctionAdded { signature } => write!(f, "function `{}` added", signature),
+            Self::OutputsChanged {
+                signature,
+                old,
+                new,
+            } => write!(
+                f,
+                "function `{}` outputs changed from ({}) to ({})",
+                signature,
+                old.join(","),
+                new.join(",")
+            ),
+            Self::MutabilityChanged {
+                signature,
+                old,
+                new,
+            } => write!(
+                f,
+                "function `{}` state mutability changed from {} to {}",
+                signature, old, new
+            ),
+            Self::EventRemoved { signature } => write!(f, "event `{}` removed", signature),
+            Self::EventAdded { signature } => write!(f, "event `{}` added", signature),
+            Self::IndexedChanged {
+                signature,
+                old,
+                new,
+            } => write!(
+                f,
+                "event `{}` indexed parameters changed from {:?} to {:?}",
+                signature, old, new
+            ),
+            Self::AnonymousChanged {
+                signature,
+                anonymous: true,
+            } => write!(f, "event `{}` became anonymous", signature),
+            Self::AnonymousChanged { signature, .. } => {
+                write!(f, "event `{}` is no longer anonymous", signature)
+            }
+            Self::ErrorRemoved { signature } => write!(f, "error `{}` removed", signature),
+            Self::ErrorAdded { signature } => write!(f, "error `{}` added", signature),
+            Self::SelectorCollision {
+                selector,
+                signatures,
+            } => write!(
+                f,
+                "selector {} collides between functions `{}`",
+                selector,
+                signatures.join("`, `")
+            ),
+        }
+    }
+}
+
+/// Changes between two versions of contract abi, displayed as a human-readable report.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AbiDiff {
+    pub changes: Vec<AbiChange>,
+}
+
+impl AbiDiff {
+    /// Returns true if any change is breaking.
+    pub fn is_breaking(&self) -> bool {
+        self.changes.iter().any(|c| c.breaking)
+    }
+
+    /// Returns the breaking changes.
+    pub fn breaking(&self) -> impl Iterator<Item = &AbiChange> {
+        self.changes.iter().filter(|c| c.breaking)
+    }
+}
+
+impl Display for AbiDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "no abi changes");
+        }
+
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+
+        let breaking = self.breaking().count();
+
+        writeln!(f, "{} changes, {} breaking", self.changes.len(), breaking)
+    }
+}
+
+/// Compare `old` abi with `new` abi, the changes are ordered as removed or changed items of the old abi,
+/// added items of the new abi, then selector collisions.
+pub fn diff_abi(old: &[AbiField], new: &[AbiField]) -> AbiDiff {
+    let old = Items::new(old);
+    let new = Items::new(new);
+
+    let mut changes: Vec<ChangeKind> = vec![];
+
+    for (signature, function) in &old.functions {
+        let Some(new_function) = new.function(signature) else {
+            changes.push(ChangeKind::FunctionRemoved {
+                signature: signature.clone(),
+            });
+
+            continue;
+        };
+
+        let outputs = to_output_types(function);
+        let new_outputs = to_output_types(new_function);
+
+        if outputs != new_outputs {
+            changes.push(ChangeKind::OutputsChanged {
+                signature: signature.clone(),
+                old: outputs,
+                new: new_outputs,
+            });
+        }
+
+        if function.state_mutability != new_function.state_mutability {
+            changes.push(ChangeKind::MutabilityChanged {
+                signature: signature.clone(),
+                old: function.state_mutability,
+                new: new_function.state_mutability,
+            });
+        }
+    }
+
+    for (signature, event) in &old.events {
+        let Some(new_event) = new.event(signature) else {
+            changes.push(ChangeKind::EventRemoved {
+                signature: signature.clone(),
+            });
+
+            continue;
+        };
+
+        let indexed = to_indexed(event);
+        let new_indexed = to_indexed(new_event);
+
+        if indexed != new_indexed {
+            changes.push(ChangeKind::IndexedChanged {
+                signature: signature.clone(),
+                old: indexed,
+                new: new_indexed,
+            });
+        }
+
+        if event.anonymous != new_event.anonymous {
+            changes.push(ChangeKind::AnonymousChanged {
+                signature: signature.clone(),
+                anonymous: new_event.anonymous,
+            });
+        }
+    }
+
+    for signature in &old.errors {
+        if !new.errors.contains(signature) {
+            changes.push(ChangeKind::ErrorRemoved {
+                signature: signature.clone(),
+            });
+        }
+    }
+
+    for (signature, _) in &new.functions {
+        if old.function(signature).is_none() {
+            changes.push(ChangeKind::FunctionAdded {
+                signature: signature.clone(),
+            });
+        }
+    }
+
+    for (signature, _) in &new.events {
+        if old.event(signature).is_none() {
+            changes.push(ChangeKind::EventAdded {
+                signature: signature.clone(),
+            });
+        }
+    }
+
+    for signature in &new.errors {
+        if !old.errors.contains(signature) {
+            changes.push(ChangeKind::ErrorAdded {
+                signature: signature.clone(),
+            });
+        }
+    }
+
+    changes.extend(selector_collisions(&old, &new));
+
+    AbiDiff {
+        changes: changes.into_iter().map(AbiChange::from).collect(),
+    }
+}
+
+/// Functions, events and errors of one abi, with canonical signatures
+struct Items<'a> {
+    functions: Vec<(String, &'a Function)>,
+    events: Vec<(String, &'a Event)>,
+    errors: Vec<String>,
+}
+
+impl<'a> Items<'a> {
+    fn new(abi: &'a [AbiField]) -> Self {
+        let mut items = Items {
+            functions: vec![],
+            events: vec![],
+            errors: vec![],
+        };
+
+        for field in abi {
+            match field {
+                AbiField::Function(function) => {
+                    items.functions.push((function.signature(), function))
+                }
+                AbiField::Event(event) => items.events.push((event.signature(), event)),
+                AbiField::Error(error) => items.errors.push(error.signature()),
+                _ => {}
+            }
+        }
+
+        items
+    }
+
+    fn function(&self, signature: &str) -> Option<&'a Function> {
+        self.functions
+            .iter()
+            .find(|(c, _)| c == signature)
+            .map(|(_, function)| *function)
+    }
+
+    fn event(&self, signature: &str) -> Option<&'a Event> {
+        self.events
+            .iter()
+            .find(|(c, _)| c == signature)
+            .map(|(_, event)| *event)
+    }
+}
+
+fn to_output_types(function: &Function) -> Vec<String> {
+    function
+        .outputs
+        .iter()
+        .map(|c| c.canonical_type())
+        .collect()
+}
+
+fn to_indexed(event: &Event) -> Vec<usize> {
+    event
+        .inputs
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| c.indexed)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Returns selectors shared by different function signatures of both abi, which at least one is declared by `new`.
+///
+/// A collision with a function only declared by `old` is a call of the removed function dispatched to another one.
+fn selector_collisions(old: &Items, new: &Items) -> Vec<ChangeKind> {
+    let mut selectors: Vec<([u8; 4], Vec<&str>)> = vec![];
+    let mut indexes: HashMap<[u8; 4], usize> = HashMap::new();
+
+    let signatures = new
+        .functions
+        .iter()
+        .chain(old.functions.iter())
+        .map(|(signature, _)| signature.as_str());
+
+    for signature in signatures {
+        let selector = to_selector(signature);
+
+        let index = *indexes.entry(selector).or_insert_with(|| {
+            selectors.push((selector, vec![]));
+            selectors.len() - 1
+        });
+
+        let signatures = &mut selectors[index].1;
+
+        if !signatures.contains(&signature) {
+            signatures.push(signature);
+        }
+    }
+
+    selectors
+        .into_iter()
+        .filter(|(_, signatures)| {
+            signatures.len() > 1 && signatures.iter().any(|c| new.function(c).is_some())
+        })
+        .map(|(selector, signatures)| ChangeKind::SelectorCollision {
+            selector: to_hex(selector),
+            signatures: signatures.into_iter().map(|c| c.to_owned()).collect(),
+        })
+        .collect()
+}
@@ -0,0 +1,109 @@
+//! Encoding metadata of abi types, see the [`specification`](https://docs.soliditylang.org/en/latest/abi-spec.html#formal-specification-of-the-encoding).
+//!
+//! A bare `tuple` type without components is treated as the empty tuple `()`,
+//! use the `*_with` variants to query the type of a json parameter with `components`.
+
+use crate::{Parameter, SimpleType, Type};
+
+/// Size in bytes of one abi word, every encoded value is padded to a multiple of it.
+pub const WORD_SIZE: usize = 32;
+
+/// Alignment of an elementary value within its 32 bytes word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    /// Higher-order (left) aligned and padded on the right with zero bytes, e.g: `bytes<M>`, `string`.
+    Left,
+    /// Lower-order (right) aligned and padded on the left with zero bytes, or `0xff` bytes for negative signed values, e.g: `uint<M>`, `address`.
+    Right,
+}
+
+impl Type {
+    /// Returns true if the encoding of this type is placed in the tail, referenced by an offset in the head.
+    ///
+    /// `bytes`, `string` and `T[]` are dynamic, `T[k]` and tuples are dynamic if any element is dynamic.
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Simple(SimpleType::Bytes | SimpleType::String) | Self::Array(_) => true,
+            Self::ArrayM(array_m) => array_m.element.is_dynamic(),
+            Self::Tuple(types) => types.iter().any(|c| c.is_dynamic()),
+            _ => false,
+        }
+    }
+
+    /// Returns the encoded size in bytes of the static type, or `None` if the type [`is_dynamic`](Self::is_dynamic)
+    /// or the size overflows `usize`, e.g: `uint256[1000000000000000000]`.
+    pub fn static_size(&self) -> Option<usize> {
+        match self {
+            Self::Simple(SimpleType::Bytes | SimpleType::String) | Self::Array(_) => None,
+            Self::Simple(SimpleType::Tuple) => Some(0),
+            Self::ArrayM(array_m) => array_m.element.static_size()?.checked_mul(array_m.m),
+            Self::Tuple(types) => types
+                .iter()
+                .try_fold(0usize, |size, c| size.checked_add(c.static_size()?)),
+            _ => Some(WORD_SIZE),
+        }
+    }
+
+    /// Returns the size in bytes this type occupies in the head of an enclosing tuple,
+    /// which is 32 bytes offset for dynamic types, or `None` if the static size overflows `usize`.
+    pub fn head_size(&self) -> Option<usize> {
+        if self.is_dynamic() {
+            Some(WORD_SIZE)
+        } else {
+            self.static_size()
+        }
+    }
+
+    /// Returns the alignment of elementary types within the word, or `None` for arrays and tuples.
+    pub fn alignment(&self) -> Option<Alignment> {
+        match self {
+            Self::Simple(SimpleType::Bytes | SimpleType::String | SimpleType::Function)
+            | Self::BytesM(_) => Some(Alignment::Left),
+            Self::Simple(
+                SimpleType::Address
+                | SimpleType::Uint
+                | SimpleType::Int
+                | SimpleType::Bool
+                | SimpleType::Fixed
+                | SimpleType::Ufixed,
+            )
+            | Self::IntegerM(_)
+            | Self::FixedMN(_) => Some(Alignment::Right),
+            Self::Simple(SimpleType::Tuple) | Self::Tuple(_) | Self::Array(_) | Self::ArrayM(_) => {
+                None
+            }
+        }
+    }
+
+    /// Returns true if this is an elementary value type, `bytes`, `string`, arrays and tuples are reference types.
+    pub fn is_value_type(&self) -> bool {
+        !matches!(
+            self,
+            Self::Simple(SimpleType::Bytes | SimpleType::String | SimpleType::Tuple)
+                | Self::Tuple(_)
+                | Self::Array(_)
+                | Self::ArrayM(_)
+        )
+    }
+
+    /// Returns true if the indexed event parameter of this type is stored as the keccak256 hash of its encoding,
+    /// only [`value types`](Self::is_value_type) are stored as a topic directly.
+    pub fn is_hashed_topic(&self) -> bool {
+        !self.is_value_type()
+    }
+
+    /// Same as [`is_dynamic`](Self::is_dynamic), the tuple element is declared by `components`.
+    pub fn is_dynamic_with(&self, components: &[Parameter]) -> bool {
+        self.clone().attach_components(components).is_dynamic()
+    }
+
+    /// Same as [`static_size`](Self::static_size), the tuple element is declared by `components`.
+    pub fn static_size_with(&self, components: &[Parameter]) -> Option<usize> {
+        self.clone().attach_components(components).static_size()
+    }
+
+    /// Same as [`head_size`](Self::head_size), the tuple element is declared by `components`.
+    pub fn head_size_with(&self, components: &[Parameter]) -> Option<usize> {
+        self.clone().attach_components(components).head_size()
+    }
+}
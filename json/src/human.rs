@@ -0,0 +1,335 @@
+//! Human-readable abi support, e.g: `function balanceOf(address owner) view returns (uint256)`.
+//!
+//! Each signature declares one [`AbiField`], which is parsed by [`FromStr`]:
+//!
+//! - `function name(params) [external|public] [pure|view|payable|nonpayable] [returns (params)]`
+//! - `event Name(params) [anonymous]`, event parameters may be marked as `indexed`
+//! - `error Name(params)`
+//! - `constructor(params) [payable]`
+//! - `receive() external payable`
+//! - `fallback() [external] [payable]`
+//!
+//! Parameter names and data locations (`memory`, `calldata` or `storage`) are optional,
+//! tuples are declared as `(uint256 a, address b)[]` or `tuple(uint256,address)[]`.
+
+use std::{iter::Peekable, str::FromStr, vec::IntoIter};
+
+use crate::{
+    AbiError, AbiField, Constructor, Error, Event, Fallback, Function, Parameter, Receive,
+    StateMutability, Type,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Punct(char),
+}
+
+fn tokenize(signature: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = vec![];
+
+    let mut chars = signature.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '(' | ')' | '[' | ']' | ',' => tokens.push(Token::Punct(c)),
+            c if c.is_whitespace() => {}
+            c if c.is_ascii_alphanumeric() || c == '_' || c == '$' => {
+                let mut word = c.to_string();
+
+                while let Some(c) =
+                    chars.next_if(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '$')
+                {
+                    word.push(c);
+                }
+
+                tokens.push(Token::Word(word));
+            }
+            c => return Err(format!("unexpected character `{}`", c)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Peekable<IntoIter<Token>>,
+}
+
+impl Parser {
+    fn peek_word(&mut self) -> Option<&str> {
+        match self.tokens.peek() {
+            Some(Token::Word(word)) => Some(word),
+            _ => None,
+        }
+    }
+
+    fn next_word_if(&mut self, words: &[&str]) -> Option<String> {
+        match self.tokens.peek() {
+            Some(Token::Word(word)) if words.contains(&word.as_str()) => {
+                let word = word.clone();
+                self.tokens.next();
+                Some(word)
+            }
+            _ => None,
+        }
+    }
+
+    fn next_punct_if(&mut self, punct: char) -> bool {
+        self.tokens.next_if_eq(&Token::Punct(punct)).is_some()
+    }
+
+    fn expect_punct(&mut self, punct: char) -> Result<(), String> {
+        if self.next_punct_if(punct) {
+            Ok(())
+        } else {
+            Err(format!(
+                "expect `{}`, found {}",
+                punct,
+                self.describe_next()
+            ))
+        }
+    }
+
+    fn expect_word(&mut self, expected: &str) -> Result<String, String> {
+        match self.tokens.next() {
+            Some(Token::Word(word)) => Ok(word),
+            Some(Token::Punct(c)) => Err(format!("expect {}, found `{}`", expected, c)),
+            None => Err(format!("expect {}, found end of signature", expected)),
+        }
+    }
+
+    fn describe_next(&mut self) -> String {
+        match self.tokens.peek() {
+            Some(Token::Word(word)) => format!("`{}`", word),
+            Some(Token::Punct(c)) => format!("`{}`", c),
+            None => "end of signature".to_owned(),
+        }
+    }
+
+    fn expect_end(&mut self) -> Result<(), String> {
+        if self.tokens.peek().is_none() {
+            Ok(())
+        } else {
+            Err(format!("unexpected {}", self.describe_next()))
+        }
+    }
+
+    /// Parse `(param, ...)`, `indexed` is only allowed by event parameters.
+    fn parse_params(&mut self, event: bool) -> Result<Vec<Parameter>, String> {
+        self.expect_punct('(')?;
+
+        let mut params = vec![];
+
+        if self.next_punct_if(')') {
+            return Ok(params);
+        }
+
+        loop {
+            params.push(self.parse_param(event)?);
+
+            if self.next_punct_if(')') {
+                return Ok(params);
+            }
+
+            self.expect_punct(',')?;
+        }
+    }
+
+    fn parse_param(&mut self, event: bool) -> Result<Parameter, String> {
+        let (r#type, components) = self.parse_type()?;
+
+        let mut indexed = false;
+
+        // `address payable` is encoded as `address`
+        if r#type == "address" {
+            self.next_word_if(&["payable"]);
+        }
+
+        if event && self.next_word_if(&["indexed"]).is_some() {
+            indexed = true;
+        }
+
+        self.next_word_if(&["memory", "calldata", "storage"]);
+
+        let name = match self.peek_word() {
+            Some(_) => self.expect_word("parameter name")?,
+            None => String::new(),
+        };
+
+        let mut r#type: Type = r#type
+            .parse()
+            .map_err(|err| format!("invalid type `{}`, {}", r#type, err))?;
+
+        if let Some(components) = &components {
+            r#type = r#type.attach_components(components);
+        }
+
+        Ok(Parameter {
+            name,
+            r#type,
+            components,
+            indexed,
+            internal_type: None,
+        })
+    }
+
+    /// Parse type with array suffixes, tuple type is returned as `tuple[..]` with the components.
+    fn parse_type(&mut self) -> Result<(String, Option<Vec<Parameter>>), String> {
+        let (mut r#type, components) = if matches!(self.tokens.peek(), Some(Token::Punct('('))) {
+            ("tuple".to_owned(), Some(self.parse_params(false)?))
+        } else {
+            let word = self.expect_word("parameter type")?;
+
+            if word == "tuple" {
+                (word, Some(self.parse_params(false)?))
+            } else {
+                (word, None)
+            }
+        };
+
+        while self.next_punct_if('[') {
+            if self.next_punct_if(']') {
+                r#type.push_str("[]");
+            } else {
+                let m = self.expect_word("array length")?;
+
+                self.expect_punct(']')?;
+
+                r#type.push_str(&format!("[{}]", m));
+            }
+        }
+
+        Ok((r#type, components))
+    }
+
+    /// Parse function modifiers, returns the declared state mutability.
+    fn parse_modifiers(&mut self) -> StateMutability {
+        let mut state_mutability = StateMutability::Nonpayable;
+
+        while let Some(word) = self.next_word_if(&[
+            "external",
+            "public",
+            "virtual",
+            "override",
+            "pure",
+            "view",
+            "constant",
+            "payable",
+            "nonpayable",
+        ]) {
+            match word.as_str() {
+                "pure" => state_mutability = StateMutability::Pure,
+                "view" | "constant" => state_mutability = StateMutability::View,
+                "payable" => state_mutability = StateMutability::Payable,
+                "nonpayable" => state_mutability = StateMutability::Nonpayable,
+                _ => {}
+            }
+        }
+
+        state_mutability
+    }
+
+    fn parse_field(&mut self) -> Result<AbiField, String> {
+        let keyword = self
+            .expect_word("`function`, `event`, `error`, `constructor`, `receive` or `fallback`")?;
+
+        let field = match keyword.as_str() {
+            "function" => {
+                let name = self.expect_word("function name")?;
+                let inputs = self.parse_params(false)?;
+                let state_mutability = self.parse_modifiers();
+
+                let outputs = if self.next_word_if(&["returns"]).is_some() {
+                    self.parse_params(false)?
+                } else {
+                    vec![]
+                };
+
+                AbiField::Function(Function {
+                    name,
+                    inputs,
+                    outputs,
+                    state_mutability,
+                })
+            }
+            "event" => {
+                let name = self.expect_word("event name")?;
+                let inputs = self.parse_params(true)?;
+                let anonymous = self.next_word_if(&["anonymous"]).is_some();
+
+                AbiField::Event(Event {
+                    name,
+                    inputs,
+                    anonymous,
+                })
+            }
+            "error" => {
+                let name = self.expect_word("error name")?;
+                let inputs = self.parse_params(false)?;
+
+                AbiField::Error(Error { name, inputs })
+            }
+            "constructor" => {
+                let inputs = self.parse_params(false)?;
+                let state_mutability = self.parse_modifiers();
+
+                AbiField::Constructor(Constructor {
+                    inputs,
+                    state_mutability,
+                })
+            }
+            "receive" => {
+                self.expect_punct('(')?;
+                self.expect_punct(')')?;
+                self.parse_modifiers();
+
+                AbiField::Receive(Receive {
+                    state_mutability: StateMutability::Payable,
+                })
+            }
+            "fallback" => {
+                // `fallback(bytes calldata input) external returns (bytes memory output)`
+                self.parse_params(false)?;
+                let state_mutability = self.parse_modifiers();
+
+                if self.next_word_if(&["returns"]).is_some() {
+                    self.parse_params(false)?;
+                }
+
+                AbiField::Fallback(Fallback { state_mutability })
+            }
+            _ => {
+                return Err(format!(
+                    "expect `function`, `event`, `error`, `constructor`, `receive` or `fallback`, found `{}`",
+                    keyword
+                ))
+            }
+        };
+
+        self.expect_end()?;
+
+        Ok(field)
+    }
+}
+
+impl FromStr for AbiField {
+    type Err = AbiError;
+
+    fn from_str(signature: &str) -> Result<Self, Self::Err> {
+        let to_error = |err: String| AbiError::HumanReadable(signature.to_owned(), err);
+
+        let tokens = tokenize(signature).map_err(to_error)?;
+
+        Parser {
+            tokens: tokens.into_iter().peekable(),
+        }
+        .parse_field()
+        .map_err(to_error)
+    }
+}
+
+/// Parse human-readable abi `signatures`, see [`module`](self) document for the syntax.
+pub fn parse_human_readable<S: AsRef<str>>(signatures: &[S]) -> Result<Vec<AbiField>, AbiError> {
+    signatures.iter().map(|c| c.as_ref().parse()).collect()
+}
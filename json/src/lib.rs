@@ -4,11 +4,26 @@
 
 use std::{fmt::Display, str::FromStr};
 
-use regex::Regex;
 use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
 
 use thiserror::Error;
 
+mod natspec;
+pub use natspec::*;
+
+mod parser;
+use parser::parse_type;
+
+mod encoding;
+pub use encoding::{Alignment, WORD_SIZE};
+
+pub mod human;
+pub use human::parse_human_readable;
+
+pub mod diff;
+pub use diff::diff_abi;
+
 #[derive(Debug, Error)]
 pub enum AbiError {
     #[error("Invalid fixed type declare {0}, {1}")]
@@ -31,6 +46,9 @@ pub enum AbiError {
 
     #[error("Invalid Type declare {0}")]
     UnknownType(String),
+
+    #[error("Invalid human-readable abi `{0}`, {1}")]
+    HumanReadable(String, String),
 }
 
 /// Hardhat generate artifact
@@ -44,6 +62,79 @@ pub struct HardhatArtifact {
     pub deployed_bytecode: String,
 }
 
+/// Foundry generate artifact, e.g: `out/Lock.sol/Lock.json`
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FoundryArtifact {
+    pub abi: Vec<AbiField>,
+    pub bytecode: FoundryBytecode,
+    pub deployed_bytecode: FoundryBytecode,
+    /// Only exists if `extra_output` contains `userdoc`
+    pub userdoc: Option<UserDoc>,
+    /// Only exists if `extra_output` contains `devdoc`
+    pub devdoc: Option<DevDoc>,
+    pub metadata: Option<FoundryMetadata>,
+}
+
+impl FoundryArtifact {
+    /// Returns contract name declared by metadata `compilationTarget` field
+    pub fn contract_name(&self) -> Option<&str> {
+        self.metadata
+            .as_ref()?
+            .settings
+            .compilation_target
+            .values()
+            .next()
+            .map(|c| c.as_str())
+    }
+
+    /// Returns contract `NatSpec` documentation, the `userdoc`/`devdoc` fields take precedence over the metadata output.
+    pub fn natspec(&self) -> NatSpec {
+        let output = self.metadata.as_ref().map(|c| &c.output);
+
+        NatSpec::new(
+            self.userdoc
+                .clone()
+                .or_else(|| output.and_then(|c| c.userdoc.clone())),
+            self.devdoc
+                .clone()
+                .or_else(|| output.and_then(|c| c.devdoc.clone())),
+        )
+    }
+}
+
+/// Foundry artifact bytecode object
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FoundryBytecode {
+    pub object: String,
+}
+
+/// Solc compiler metadata included in foundry artifact
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FoundryMetadata {
+    #[serde(default)]
+    pub settings: FoundryMetadataSettings,
+    #[serde(default)]
+    pub output: FoundryMetadataOutput,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FoundryMetadataSettings {
+    /// Source file name to contract name map
+    #[serde(default)]
+    pub compilation_target: std::collections::HashMap<String, String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FoundryMetadataOutput {
+    pub userdoc: Option<UserDoc>,
+    pub devdoc: Option<DevDoc>,
+}
+
 /// Contract interface type enum
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", tag = "type")]
@@ -82,32 +173,40 @@ impl Function {
         format!("{}{}", self.name, tuple)
     }
 
+    /// Function selector, the first 4 bytes of keccak256 hash of the [`signature`](Function::signature)
+    pub fn selector(&self) -> [u8; 4] {
+        to_selector(&self.signature())
+    }
+
     fn to_signature(params: &[Parameter]) -> String {
-        let mut pairs = vec![];
-
-        for param in params.iter() {
-            if let Some(components) = &param.components {
-                let element = Self::to_signature(components);
-                match &param.r#type {
-                    Type::Array(_) => {
-                        pairs.push(format!("{}[]", element));
-                    }
-                    Type::ArrayM(array_m) => {
-                        pairs.push(format!("{}[{}]", element, array_m.m));
-                    }
-                    _ => {
-                        pairs.push(format!("{}", element));
-                    }
-                }
-            } else {
-                pairs.push(format!("{}", param.r#type));
-            }
-        }
+        let pairs = params
+            .iter()
+            .map(|c| c.canonical_type())
+            .collect::<Vec<_>>();
 
         format!("({})", pairs.join(","))
     }
 }
 
+/// Returns keccak256 hash of `data`
+pub fn keccak256<D: AsRef<[u8]>>(data: D) -> [u8; 32] {
+    Keccak256::new().chain_update(data).finalize().into()
+}
+
+/// Returns `0x` prefixed lower case hex string of `data`
+pub fn to_hex<D: AsRef<[u8]>>(data: D) -> String {
+    let hex: String = data.as_ref().iter().map(|b| format!("{:02x}", b)).collect();
+
+    format!("0x{}", hex)
+}
+
+/// Returns the first 4 bytes of keccak256 hash of function/error `signature`, e.g: `transfer(address,uint256)`
+pub fn to_selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature);
+
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
 fn default_parameters() -> Vec<Parameter> {
     vec![]
 }
@@ -127,35 +226,10 @@ pub struct Constructor {
 impl Constructor {
     /// Only include inputs,e.g: withdraw(address)
     pub fn signature(&self) -> String {
-        let tuple = Self::to_signature(&self.inputs);
+        let tuple = Function::to_signature(&self.inputs);
 
         format!("Constructor{}", tuple)
     }
-
-    fn to_signature(params: &[Parameter]) -> String {
-        let mut pairs = vec![];
-
-        for param in params.iter() {
-            if let Some(components) = &param.components {
-                let element = Self::to_signature(components);
-                match &param.r#type {
-                    Type::Array(_) => {
-                        pairs.push(format!("{}[]", element));
-                    }
-                    Type::ArrayM(array_m) => {
-                        pairs.push(format!("{}[{}]", element, array_m.m));
-                    }
-                    _ => {
-                        pairs.push(format!("{}", element));
-                    }
-                }
-            } else {
-                pairs.push(format!("{}", param.r#type));
-            }
-        }
-
-        format!("({})", pairs.join(","))
-    }
 }
 
 /// A structure type to represent `receive function` abi
@@ -178,7 +252,7 @@ pub struct Fallback {
     pub state_mutability: StateMutability,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum StateMutability {
     Pure,
@@ -187,6 +261,13 @@ pub enum StateMutability {
     Payable,
 }
 
+impl Display for StateMutability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = serde_json::to_string(self).expect("Serialize type to json");
+        write!(f, "{}", &value[1..value.len() - 1])
+    }
+}
+
 /// A structure type to represent `event` abi
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -199,6 +280,18 @@ pub struct Event {
     pub anonymous: bool,
 }
 
+impl Event {
+    /// Event canonical signature, e.g: Transfer(address,address,uint256)
+    pub fn signature(&self) -> String {
+        format!("{}{}", self.name, Function::to_signature(&self.inputs))
+    }
+
+    /// Event topic, the keccak256 hash of the [`signature`](Event::signature)
+    pub fn topic(&self) -> [u8; 32] {
+        keccak256(self.signature())
+    }
+}
+
 /// A structure type to represent `event` abi
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -208,27 +301,141 @@ pub struct Error {
     /// An array of function's input params
     pub inputs: Vec<Parameter>,
 }
+
+impl Error {
+    /// Error canonical signature, e.g: InsufficientBalance(uint256,uint256)
+    pub fn signature(&self) -> String {
+        format!("{}{}", self.name, Function::to_signature(&self.inputs))
+    }
+
+    /// Error selector, the first 4 bytes of keccak256 hash of the [`signature`](Error::signature)
+    pub fn selector(&self) -> [u8; 4] {
+        to_selector(&self.signature())
+    }
+}
+
 /// Handle Function/Event/Error 's input or output parameter type
+///
+/// The tuple `components` are also attached to [`type`](Parameter::type), e.g: json `tuple[]` is parsed as `(address,uint256)[]`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(from = "RawParameter", into = "RawParameter")]
 pub struct Parameter {
     /// The name of the parameter
     pub name: String,
-    /// The canonical type of the parameter
+    /// The structured type of the parameter
     pub r#type: Type,
-    /// used for tuple types, only if the type field start with prefix `tuple`. e.g, `tupe[]`,`tuple`
+    /// used for tuple types, only if the type field start with prefix `tuple`. e.g, `tupe[]`,`tuple`,
+    /// keeps the component names and internal types
     pub components: Option<Vec<Parameter>>,
     /// This field is only meaningful for `Event` or `Error`
-    #[serde(default = "default_indexed")]
     pub indexed: bool,
     /// Hardhat extension field
     pub internal_type: Option<String>,
 }
 
+impl Parameter {
+    /// Returns the type with tuple components attached, e.g: `tuple[]` => `(address,uint256)[]`
+    pub fn structured_type(&self) -> Type {
+        match &self.components {
+            Some(components) => self.r#type.clone().attach_components(components),
+            None => self.r#type.clone(),
+        }
+    }
+
+    /// Returns canonical type of this parameter, tuple components are expanded and type aliases are replaced,
+    /// e.g: `(address,uint256)[]`, `uint` => `uint256`
+    pub fn canonical_type(&self) -> String {
+        self.structured_type().canonical().to_string()
+    }
+
+    /// Returns struct name declared by `internalType` field, e.g: `struct IMaker.Metadata[]` => `Metadata`
+    pub fn struct_name(&self) -> Option<&str> {
+        self.struct_path()?.rsplit('.').next()
+    }
+
+    /// Returns struct path declared by `internalType` field, e.g: `struct IMaker.Metadata[]` => `IMaker.Metadata`
+    pub fn struct_path(&self) -> Option<&str> {
+        let internal_type = self.internal_type.as_ref()?.strip_prefix("struct ")?;
+
+        internal_type.split('[').next()
+    }
+}
+
 fn default_indexed() -> bool {
     false
 }
 
+/// Json form of [`Parameter`], tuple type is declared as `tuple` with the `components` field.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawParameter {
+    name: String,
+    r#type: Type,
+    components: Option<Vec<Parameter>>,
+    #[serde(default = "default_indexed")]
+    indexed: bool,
+    internal_type: Option<String>,
+}
+
+impl From<RawParameter> for Parameter {
+    fn from(raw: RawParameter) -> Self {
+        let r#type = match &raw.components {
+            Some(components) => raw.r#type.attach_components(components),
+            None => raw.r#type,
+        };
+
+        Self {
+            name: raw.name,
+            r#type,
+            components: raw.components,
+            indexed: raw.indexed,
+            internal_type: raw.internal_type,
+        }
+    }
+}
+
+impl From<Parameter> for RawParameter {
+    fn from(param: Parameter) -> Self {
+        // Tuple parsed from type string has no components, which are declared as unnamed parameters.
+        let components = param.components.or_else(|| {
+            param
+                .r#type
+                .tuple_element()
+                .map(|types| types.iter().map(|c| Parameter::from(c.clone())).collect())
+        });
+
+        let r#type = match &components {
+            Some(_) => param.r#type.detach_components(),
+            None => param.r#type,
+        };
+
+        Self {
+            name: param.name,
+            r#type,
+            components,
+            indexed: param.indexed,
+            internal_type: param.internal_type,
+        }
+    }
+}
+
+impl From<Type> for Parameter {
+    /// Unnamed parameter of `type`, tuple components are unnamed too.
+    fn from(r#type: Type) -> Self {
+        let components = r#type
+            .tuple_element()
+            .map(|types| types.iter().map(|c| Parameter::from(c.clone())).collect());
+
+        Self {
+            name: String::new(),
+            r#type,
+            components,
+            indexed: false,
+            internal_type: None,
+        }
+    }
+}
+
 /// Contract abi simple types enum
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -246,18 +453,18 @@ pub enum SimpleType {
     Tuple,
 }
 
-impl ToString for SimpleType {
-    fn to_string(&self) -> String {
+impl Display for SimpleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // to canonical type name
         match self {
-            Self::Ufixed => "fixed128x18".into(),
-            Self::Fixed => "ufixed128x18".into(),
-            Self::Int => "int256".into(),
-            Self::Uint => "uint256".into(),
+            Self::Fixed => write!(f, "fixed128x18"),
+            Self::Ufixed => write!(f, "ufixed128x18"),
+            Self::Int => write!(f, "int256"),
+            Self::Uint => write!(f, "uint256"),
             _ => {
-                let data = serde_json::to_string(self).unwrap();
+                let data = serde_json::to_string(self).expect("Serialize type to json");
 
-                data[1..data.len() - 1].to_string()
+                write!(f, "{}", &data[1..data.len() - 1])
             }
         }
     }
@@ -265,10 +472,7 @@ impl ToString for SimpleType {
 
 impl SimpleType {
     pub fn is_tuple(&self) -> bool {
-        match self {
-            Self::Tuple => true,
-            _ => false,
-        }
+        matches!(self, Self::Tuple)
     }
 }
 
@@ -300,10 +504,6 @@ impl Serialize for FixedMN {
     }
 }
 
-fn fixed_regex() -> Regex {
-    Regex::new(r"^(u){0,1}fixed(\d{1,3})x(\d{1,3})$").unwrap()
-}
-
 impl<'de> Deserialize<'de> for FixedMN {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -311,36 +511,14 @@ impl<'de> Deserialize<'de> for FixedMN {
     {
         let data = String::deserialize(deserializer)?;
 
-        if let Some(captures) = fixed_regex().captures(&data) {
-            let signed = captures.get(1).map(|_| false).unwrap_or(true);
-
-            let m: usize = (&captures[2]).parse().map_err(serde::de::Error::custom)?;
-            let n: usize = (&captures[3]).parse().map_err(serde::de::Error::custom)?;
-
-            if m < 8 || m > 256 || m % 8 != 0 {
-                return Err(AbiError::FixedMN(
-                    data,
-                    "M bits must meet the condition 0 < M <= 256, M % 8 == 0".to_string(),
-                ))
-                .map_err(serde::de::Error::custom);
-            }
-
-            if n > 80 {
-                return Err(AbiError::FixedMN(
-                    data,
-                    "decimal numbers N must meet the condition 0 < N <= 80".to_string(),
-                ))
-                .map_err(serde::de::Error::custom);
-            }
-
-            Ok(Self { signed, m, n })
-        } else {
-            return Err(AbiError::FixedMN(
+        match parse_type(&data) {
+            Ok(Type::FixedMN(fixed_m_n)) => Ok(fixed_m_n),
+            Err(err @ AbiError::FixedMN(..)) => Err(serde::de::Error::custom(err)),
+            _ => Err(serde::de::Error::custom(AbiError::FixedMN(
                 data,
                 "{u}fixed<M>x<N>: fixed-point decimal number of M bits, 8 <= M <= 256, M % 8 == 0, and 0 < N <= 80"
                     .to_string(),
-            ))
-            .map_err(serde::de::Error::custom);
+            ))),
         }
     }
 }
@@ -372,10 +550,6 @@ impl Serialize for IntegerM {
     }
 }
 
-fn integer_regex() -> Regex {
-    Regex::new(r"^(u){0,1}int(\d{1,3})$").unwrap()
-}
-
 impl<'de> Deserialize<'de> for IntegerM {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -383,26 +557,13 @@ impl<'de> Deserialize<'de> for IntegerM {
     {
         let data = String::deserialize(deserializer)?;
 
-        if let Some(captures) = integer_regex().captures(&data) {
-            let signed = captures.get(1).map(|_| false).unwrap_or(true);
-
-            let m: usize = (&captures[2]).parse().map_err(serde::de::Error::custom)?;
-
-            if m < 8 || m > 256 || m % 8 != 0 {
-                return Err(AbiError::IntegerM(
-                    data,
-                    "M bits must meet the condition 0 < M <= 256, M % 8 == 0".to_string(),
-                ))
-                .map_err(serde::de::Error::custom);
-            }
-
-            Ok(Self { signed, m })
-        } else {
-            return Err(AbiError::FixedMN(
+        match parse_type(&data) {
+            Ok(Type::IntegerM(integer_m)) => Ok(integer_m),
+            Err(err @ AbiError::IntegerM(..)) => Err(serde::de::Error::custom(err)),
+            _ => Err(serde::de::Error::custom(AbiError::IntegerM(
                 data,
-                "{u}int<M>: unsigned integer type of M bits, 0 < M <= 256, M % 8 == 0".to_string(),
-            ))
-            .map_err(serde::de::Error::custom);
+                "{u}int<M>: integer type of M bits, 0 < M <= 256, M % 8 == 0".to_string(),
+            ))),
         }
     }
 }
@@ -436,21 +597,13 @@ impl<'de> Deserialize<'de> for BytesM {
     {
         let data = String::deserialize(deserializer)?;
 
-        if data.starts_with("bytes") {
-            let m: usize = (&data[5..]).parse().map_err(serde::de::Error::custom)?;
-
-            if m > 32 {
-                return Err(AbiError::BytesM(data, "0 < M <= 32".to_string()))
-                    .map_err(serde::de::Error::custom);
-            }
-
-            Ok(Self { m })
-        } else {
-            return Err(AbiError::BytesM(
+        match parse_type(&data) {
+            Ok(Type::BytesM(bytes_m)) => Ok(bytes_m),
+            Err(err @ AbiError::BytesM(..)) => Err(serde::de::Error::custom(err)),
+            _ => Err(serde::de::Error::custom(AbiError::BytesM(
                 data,
                 "bytes<M>: binary type of M bytes, 0 < M <= 32".to_string(),
-            ))
-            .map_err(serde::de::Error::custom);
+            ))),
         }
     }
 }
@@ -467,6 +620,87 @@ pub enum Type {
 
     ArrayM(Box<ArrayM>),
     Array(Box<Array>),
+
+    /// Tuple of the component types, e.g: `(address,uint256)`
+    Tuple(Vec<Type>),
+}
+
+impl Type {
+    /// Returns the component types of tuple or array of tuples, e.g: `(address,uint256)[]` => `[address, uint256]`
+    pub fn tuple_element(&self) -> Option<&[Type]> {
+        match self {
+            Self::Tuple(types) => Some(types),
+            Self::Array(array) => array.element.tuple_element(),
+            Self::ArrayM(array_m) => array_m.element.tuple_element(),
+            _ => None,
+        }
+    }
+
+    /// Replace the tuple element with the types of `components`, e.g: `tuple[]` => `(address,uint256)[]`
+    pub fn attach_components(self, components: &[Parameter]) -> Self {
+        match self {
+            Self::Simple(SimpleType::Tuple) | Self::Tuple(_) => {
+                Self::Tuple(components.iter().map(|c| c.structured_type()).collect())
+            }
+            Self::Array(array) => Self::Array(Box::new(Array {
+                element: array.element.attach_components(components),
+            })),
+            Self::ArrayM(array_m) => Self::ArrayM(Box::new(ArrayM {
+                element: array_m.element.attach_components(components),
+                m: array_m.m,
+            })),
+            r#type => r#type,
+        }
+    }
+
+    /// Replace the tuple element with `tuple`, which is the json abi form, e.g: `(address,uint256)[]` => `tuple[]`
+    pub fn detach_components(&self) -> Self {
+        match self {
+            Self::Tuple(_) => Self::Simple(SimpleType::Tuple),
+            Self::Array(array) => Self::Array(Box::new(Array {
+                element: array.element.detach_components(),
+            })),
+            Self::ArrayM(array_m) => Self::ArrayM(Box::new(ArrayM {
+                element: array_m.element.detach_components(),
+                m: array_m.m,
+            })),
+            r#type => r#type.clone(),
+        }
+    }
+
+    /// Replace the type aliases with their canonical types, which are used by signatures,
+    /// e.g: `(uint,fixed)[]` => `(uint256,fixed128x18)[]`
+    pub fn canonical(self) -> Self {
+        match self {
+            Self::Simple(SimpleType::Uint) => Self::IntegerM(IntegerM {
+                signed: false,
+                m: 256,
+            }),
+            Self::Simple(SimpleType::Int) => Self::IntegerM(IntegerM {
+                signed: true,
+                m: 256,
+            }),
+            Self::Simple(SimpleType::Fixed) => Self::FixedMN(FixedMN {
+                signed: true,
+                m: 128,
+                n: 18,
+            }),
+            Self::Simple(SimpleType::Ufixed) => Self::FixedMN(FixedMN {
+                signed: false,
+                m: 128,
+                n: 18,
+            }),
+            Self::Array(array) => Self::Array(Box::new(Array {
+                element: array.element.canonical(),
+            })),
+            Self::ArrayM(array_m) => Self::ArrayM(Box::new(ArrayM {
+                element: array_m.element.canonical(),
+                m: array_m.m,
+            })),
+            Self::Tuple(types) => Self::Tuple(types.into_iter().map(Self::canonical).collect()),
+            r#type => r#type,
+        }
+    }
 }
 
 impl From<Type> for String {
@@ -478,9 +712,9 @@ impl From<Type> for String {
 }
 
 impl FromStr for Type {
-    type Err = serde_json::Error;
+    type Err = AbiError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        serde_json::from_str(&format!("\"{}\"", s))
+        parse_type(s)
     }
 }
 
@@ -503,6 +737,11 @@ impl Serialize for Type {
             Self::FixedMN(fixed_m) => fixed_m.serialize(serializer),
             Self::ArrayM(array_m) => array_m.serialize(serializer),
             Self::Array(array) => array.serialize(serializer),
+            Self::Tuple(types) => {
+                let types = types.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+
+                serializer.serialize_str(&format!("({})", types.join(",")))
+            }
         }
     }
 }
@@ -514,33 +753,7 @@ impl<'de> Deserialize<'de> for Type {
     {
         let data = String::deserialize(deserializer)?;
 
-        let data = format!("\"{}\"", data);
-
-        if let Ok(array_m) = serde_json::from_str::<ArrayM>(&data) {
-            return Ok(Self::ArrayM(Box::new(array_m)));
-        }
-
-        if let Ok(array) = serde_json::from_str::<Array>(&data) {
-            return Ok(Self::Array(Box::new(array)));
-        }
-
-        if let Ok(fixed_m_n) = serde_json::from_str::<FixedMN>(&data) {
-            return Ok(Self::FixedMN(fixed_m_n));
-        }
-
-        if let Ok(integer_m) = serde_json::from_str::<IntegerM>(&data) {
-            return Ok(Self::IntegerM(integer_m));
-        }
-
-        if let Ok(bytes_m) = serde_json::from_str::<BytesM>(&data) {
-            return Ok(Self::BytesM(bytes_m));
-        }
-
-        if let Ok(simple_type) = serde_json::from_str::<SimpleType>(&data) {
-            return Ok(Self::Simple(simple_type));
-        }
-
-        return Err(AbiError::UnknownType(data)).map_err(serde::de::Error::custom);
+        parse_type(&data).map_err(serde::de::Error::custom)
     }
 }
 
@@ -570,34 +783,21 @@ impl Serialize for ArrayM {
     }
 }
 
-fn array_m_regex() -> Regex {
-    Regex::new(r"\[(\d{1,3})\]$").unwrap()
-}
-
 impl<'de> Deserialize<'de> for ArrayM {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let array_m = String::deserialize(deserializer)?;
-
-        let end_with_regex = array_m_regex();
-
-        if let Some(caps) = end_with_regex.captures(&array_m) {
-            let m: usize = (&caps[1]).parse().map_err(serde::de::Error::custom)?;
-
-            let data = format!("\"{}\"", &array_m[..array_m.len() - caps.len() - 2]);
-
-            let element: Type = serde_json::from_str(&data).map_err(serde::de::Error::custom)?;
+        let data = String::deserialize(deserializer)?;
 
-            return Ok(Self { element, m });
-        } else {
-            return Err(AbiError::ArrayM(
-                array_m,
+        match parse_type(&data) {
+            Ok(Type::ArrayM(array_m)) => Ok(*array_m),
+            Err(err) => Err(serde::de::Error::custom(err)),
+            _ => Err(serde::de::Error::custom(AbiError::ArrayM(
+                data,
                 "<type>[M]: a fixed-length array of M elements, M >= 0, of the given type"
                     .to_string(),
-            ))
-            .map_err(serde::de::Error::custom);
+            ))),
         }
     }
 }
@@ -631,19 +831,15 @@ impl<'de> Deserialize<'de> for Array {
     where
         D: serde::Deserializer<'de>,
     {
-        let array_m = String::deserialize(deserializer)?;
-
-        if array_m.ends_with("[]") {
-            let data = format!("\"{}\"", &array_m[..array_m.len() - 2]);
-            let element: Type = serde_json::from_str(&data).map_err(serde::de::Error::custom)?;
+        let data = String::deserialize(deserializer)?;
 
-            return Ok(Self { element });
-        } else {
-            return Err(AbiError::Array(
-                array_m,
+        match parse_type(&data) {
+            Ok(Type::Array(array)) => Ok(*array),
+            Err(err) => Err(serde::de::Error::custom(err)),
+            _ => Err(serde::de::Error::custom(AbiError::Array(
+                data,
                 "<type>[]: a variable-length array of elements of the given type.".to_string(),
-            ))
-            .map_err(serde::de::Error::custom);
+            ))),
         }
     }
 }
@@ -651,42 +847,49 @@ impl<'de> Deserialize<'de> for Array {
 #[cfg(test)]
 mod tests {
 
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
-    fn test_fixed_regex() {
+    fn test_fixed_parse() {
         _ = pretty_env_logger::try_init();
-        let re = fixed_regex();
-
-        assert!(re.is_match("ufixed100x18"));
-
-        assert!(re.is_match("fixed100x18"));
 
-        assert!(!re.is_match("fixed1000x18"));
+        assert!("ufixed128x18".parse::<Type>().is_ok());
 
-        assert!(!re.is_match("ufixed1000x18"));
-        assert!(!re.is_match("uufixed1000x18"));
+        assert!("fixed128x18".parse::<Type>().is_ok());
 
-        assert!(!re.is_match("fixed-100x18"));
-
-        if let Some(captures) = fixed_regex().captures("fixed128x18") {
-            assert_eq!(captures.get(1), None);
-            assert_eq!(captures.get(2).map(|c| c.as_str()), Some("128"));
-            assert_eq!(captures.get(3).map(|c| c.as_str()), Some("18"));
+        for invalid in [
+            "fixed100x18",
+            "fixed1000x18",
+            "ufixed1000x18",
+            "uufixed128x18",
+            "fixed-128x18",
+        ] {
+            assert!(invalid.parse::<Type>().is_err(), "{}", invalid);
         }
+
+        assert_eq!(
+            "fixed128x18".parse::<Type>().unwrap(),
+            Type::FixedMN(FixedMN {
+                signed: true,
+                m: 128,
+                n: 18
+            })
+        );
     }
 
     #[test]
     fn test_fixed_json() {
         let fixed: FixedMN = serde_json::from_str(r#""fixed128x18""#).expect("Parse fixed");
 
-        assert_eq!(fixed.signed, true);
+        assert!(fixed.signed);
         assert_eq!(fixed.m, 128);
         assert_eq!(fixed.n, 18);
 
         let fixed: FixedMN = serde_json::from_str(r#""ufixed128x18""#).expect("Parse fixed");
 
-        assert_eq!(fixed.signed, false);
+        assert!(!fixed.signed);
         assert_eq!(fixed.m, 128);
         assert_eq!(fixed.n, 18);
 
@@ -699,24 +902,582 @@ mod tests {
     fn test_int_json() {
         let fixed: IntegerM = serde_json::from_str(r#""int128""#).expect("Parse integer");
 
-        assert_eq!(fixed.signed, true);
+        assert!(fixed.signed);
         assert_eq!(fixed.m, 128);
 
         let fixed: IntegerM = serde_json::from_str(r#""uint128""#).expect("Parse integer");
 
-        assert_eq!(fixed.signed, false);
+        assert!(!fixed.signed);
         assert_eq!(fixed.m, 128);
 
         serde_json::from_str::<IntegerM>(r#""uint100""#).expect_err("M % 8 == 0");
     }
 
     #[test]
-    fn test_end_with() {
-        let end_with_regex = array_m_regex();
+    fn test_fixed_alias() {
+        assert_eq!(SimpleType::Fixed.to_string(), "fixed128x18");
+        assert_eq!(SimpleType::Ufixed.to_string(), "ufixed128x18");
+    }
+
+    #[test]
+    fn test_array_m() {
+        let array_m: ArrayM = serde_json::from_str(r#""uint8[1][123]""#).expect("Parse array");
+
+        assert_eq!(array_m.m, 123);
+        assert_eq!(array_m.element.to_string(), "uint8[1]");
+
+        for (data, m) in [
+            ("uint256[100]", 100),
+            ("bool[1000]", 1000),
+            ("bytes32[1]", 1),
+        ] {
+            match data.parse::<Type>().expect("Parse array") {
+                Type::ArrayM(array_m) => assert_eq!(array_m.m, m),
+                r#type => panic!("Expect fixed-length array, found {:?}", r#type),
+            }
+        }
+    }
+
+    #[test]
+    fn test_type_errors() {
+        assert!(matches!(
+            "bytes0".parse::<Type>(),
+            Err(AbiError::BytesM(..))
+        ));
+        assert!(matches!(
+            "bytes33".parse::<Type>(),
+            Err(AbiError::BytesM(..))
+        ));
+        assert!(matches!(
+            "uint7".parse::<Type>(),
+            Err(AbiError::IntegerM(..))
+        ));
+        assert!(matches!(
+            "int264".parse::<Type>(),
+            Err(AbiError::IntegerM(..))
+        ));
+        assert!(matches!(
+            "uint99999999999999999999999".parse::<Type>(),
+            Err(AbiError::IntegerM(..))
+        ));
+        assert!(matches!(
+            "fixed128x81".parse::<Type>(),
+            Err(AbiError::FixedMN(..))
+        ));
+        assert!(matches!(
+            "ufixed128x0".parse::<Type>(),
+            Err(AbiError::FixedMN(..))
+        ));
+        assert!(matches!(
+            "fixed0128x18".parse::<Type>(),
+            Err(AbiError::FixedMN(..))
+        ));
+        assert!(matches!(
+            "uint08".parse::<Type>(),
+            Err(AbiError::IntegerM(..))
+        ));
+        assert!(matches!(
+            "bytes01".parse::<Type>(),
+            Err(AbiError::BytesM(..))
+        ));
+        assert!(matches!(
+            "uint256[".parse::<Type>(),
+            Err(AbiError::Array(..))
+        ));
+        assert!(matches!(
+            "uint256[+1]".parse::<Type>(),
+            Err(AbiError::ArrayM(..))
+        ));
+        assert!(matches!(
+            "uint256[x]".parse::<Type>(),
+            Err(AbiError::ArrayM(..))
+        ));
+        assert!(matches!(
+            "uint256[0]".parse::<Type>(),
+            Err(AbiError::ArrayM(..))
+        ));
+        assert!(matches!(
+            "uint256[01]".parse::<Type>(),
+            Err(AbiError::ArrayM(..))
+        ));
+        assert!(matches!(
+            "uint256]".parse::<Type>(),
+            Err(AbiError::UnknownType(..))
+        ));
+        assert!(matches!(
+            "[]".parse::<Type>(),
+            Err(AbiError::UnknownType(..))
+        ));
+        assert!(matches!(
+            "uint256 ".parse::<Type>(),
+            Err(AbiError::UnknownType(..))
+        ));
+        assert!(matches!(
+            "address2".parse::<Type>(),
+            Err(AbiError::UnknownType(..))
+        ));
+
+        assert!(serde_json::from_str::<BytesM>(r#""bytes0""#).is_err());
+        assert!(serde_json::from_str::<IntegerM>(r#""uint""#).is_err());
+    }
+
+    #[test]
+    fn test_tuple_type() {
+        assert_eq!(
+            "tuple(uint256,(bool,bytes32)[2])[]"
+                .parse::<Type>()
+                .unwrap()
+                .to_string(),
+            "(uint256,(bool,bytes32)[2])[]"
+        );
+
+        assert!(matches!(
+            "(uint256".parse::<Type>(),
+            Err(AbiError::Tuple(..))
+        ));
+        assert!(matches!(
+            "(uint256 address)".parse::<Type>(),
+            Err(AbiError::Tuple(..))
+        ));
+        assert!(matches!(
+            "(uint256,)".parse::<Type>(),
+            Err(AbiError::UnknownType(..))
+        ));
+    }
+
+    #[test]
+    fn test_parameter_components() {
+        let data = serde_json::json!({
+            "name": "orders",
+            "type": "tuple[][2]",
+            "internalType": "struct Types.Order[][2]",
+            "components": [
+                { "name": "maker", "type": "address", "internalType": "address", "indexed": false, "components": null },
+                {
+                    "name": "fees",
+                    "type": "tuple[]",
+                    "internalType": "struct Types.Fee[]",
+                    "indexed": false,
+                    "components": [
+                        { "name": "amount", "type": "uint256", "internalType": "uint256", "indexed": false, "components": null }
+                    ]
+                }
+            ],
+            "indexed": false
+        });
+
+        let param: Parameter = serde_json::from_value(data.clone()).expect("Parse parameter");
+
+        assert_eq!(param.r#type.to_string(), "(address,(uint256)[])[][2]");
+        assert_eq!(param.canonical_type(), "(address,(uint256)[])[][2]");
+        assert_eq!(param.r#type.tuple_element().map(|c| c.len()), Some(2));
+
+        // Lossless conversion back to the json `components` form
+        assert_eq!(
+            serde_json::to_value(&param).expect("Serialize parameter"),
+            data
+        );
+
+        // Tuple parsed from type string is declared with unnamed components
+        let param = Parameter::from("(uint256,address[])[]".parse::<Type>().unwrap());
+
+        let data = serde_json::to_value(&param).expect("Serialize parameter");
+
+        assert_eq!(data["type"], "tuple[]");
+        assert_eq!(data["components"][1]["type"], "address[]");
+        assert_eq!(data["components"][1]["name"], "");
+
+        let param: Parameter = serde_json::from_value(data).expect("Parse parameter");
+
+        assert_eq!(param.canonical_type(), "(uint256,address[])[]");
+    }
+
+    #[test]
+    fn test_type_encoding() {
+        use Alignment::*;
+
+        // (type, dynamic, static size, alignment, hashed topic)
+        type Case = (&'static str, bool, Option<usize>, Option<Alignment>, bool);
+
+        let cases: &[Case] = &[
+            ("address", false, Some(32), Some(Right), false),
+            ("bool", false, Some(32), Some(Right), false),
+            ("uint", false, Some(32), Some(Right), false),
+            ("uint8", false, Some(32), Some(Right), false),
+            ("int256", false, Some(32), Some(Right), false),
+            ("fixed", false, Some(32), Some(Right), false),
+            ("ufixed128x18", false, Some(32), Some(Right), false),
+            ("bytes1", false, Some(32), Some(Left), false),
+            ("bytes32", false, Some(32), Some(Left), false),
+            ("function", false, Some(32), Some(Left), false),
+            ("bytes", true, None, Some(Left), true),
+            ("string", true, None, Some(Left), true),
+            ("uint256[]", true, None, None, true),
+            ("uint256[3]", false, Some(96), None, true),
+            ("uint256[2][3]", false, Some(192), None, true),
+            ("uint256[][3]", true, None, None, true),
+            ("string[2]", true, None, None, true),
+            ("uint256[1]", false, Some(32), None, true),
+            ("()", false, Some(0), None, true),
+            ("tuple", false, Some(0), None, true),
+            ("(address,bytes32)", false, Some(64), None, true),
+            ("(address,bytes32)[2]", false, Some(128), None, true),
+            ("(address,(bool,uint8[2]))", false, Some(128), None, true),
+            ("(address,bytes)", true, None, None, true),
+            ("(address,(bool,string))[2]", true, None, None, true),
+            ("(address,bytes32)[]", true, None, None, true),
+        ];
+
+        for (r#type, dynamic, static_size, alignment, hashed) in cases {
+            let r#type = r#type.parse::<Type>().expect("Parse type");
+
+            assert_eq!(r#type.is_dynamic(), *dynamic, "{}", r#type);
+            assert_eq!(r#type.static_size(), *static_size, "{}", r#type);
+            assert_eq!(
+                r#type.head_size(),
+                Some(static_size.unwrap_or(WORD_SIZE)),
+                "{}",
+                r#type
+            );
+            assert_eq!(r#type.alignment(), *alignment, "{}", r#type);
+            assert_eq!(r#type.is_hashed_topic(), *hashed, "{}", r#type);
+            assert_eq!(r#type.is_value_type(), !*hashed, "{}", r#type);
+        }
+    }
+
+    #[test]
+    fn test_type_encoding_overflow() {
+        for r#type in [
+            "uint256[1000000000000000000]",
+            "uint256[1000000000000][1000000000000]",
+            "(uint256[576460752303423487],uint256[576460752303423487])",
+        ] {
+            let r#type = r#type.parse::<Type>().expect("Parse type");
+
+            assert!(!r#type.is_dynamic(), "{}", r#type);
+            assert_eq!(r#type.static_size(), None, "{}", r#type);
+            assert_eq!(r#type.head_size(), None, "{}", r#type);
+        }
+
+        // dynamic type head is offset, even if the static size of element overflows
+        let r#type = "uint256[1000000000000000000][]".parse::<Type>().unwrap();
+
+        assert_eq!(r#type.head_size(), Some(WORD_SIZE));
+    }
+
+    #[test]
+    fn test_type_encoding_with_components() {
+        let components: Vec<Parameter> = serde_json::from_str(
+            r#"[
+                { "name": "maker", "type": "address" },
+                { "name": "amounts", "type": "uint256[2]" }
+            ]"#,
+        )
+        .expect("Parse components");
+
+        let r#type = "tuple[3]".parse::<Type>().unwrap();
+
+        assert!(!r#type.is_dynamic_with(&components));
+        assert_eq!(r#type.static_size_with(&components), Some(288));
+        assert_eq!(r#type.head_size_with(&components), Some(288));
+
+        let param: Parameter = serde_json::from_value(serde_json::json!({
+            "name": "orders",
+            "type": "tuple[3]",
+            "components": components,
+        }))
+        .unwrap();
+
+        assert_eq!(param.r#type.static_size(), Some(288));
+
+        let components: Vec<Parameter> =
+            serde_json::from_str(r#"[{ "name": "data", "type": "bytes" }]"#).unwrap();
+
+        assert!(r#type.is_dynamic_with(&components));
+        assert_eq!(r#type.static_size_with(&components), None);
+        assert_eq!(r#type.head_size_with(&components), Some(WORD_SIZE));
+    }
+
+    #[test]
+    fn test_diff_abi() {
+        use diff::ChangeKind;
+
+        let old = parse_human_readable(&[
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "function balanceOf(address owner) view returns (uint256)",
+            "function decimals() view returns (uint8)",
+            "function owner() view returns (address)",
+            "function deposit() payable",
+            "function approve(address spender, uint256 amount) returns (bool)",
+            "event Transfer(address indexed from, address indexed to, uint256 amount)",
+            "event Approval(address indexed owner, address indexed spender, uint256 amount)",
+            "error Unauthorized(address caller)",
+        ])
+        .unwrap();
+
+        let new = parse_human_readable(&[
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "function balanceOf(address owner) returns (uint256)",
+            "function decimals() pure returns (uint256)",
+            "function owner() view returns (address)",
+            "function deposit()",
+            "function approve(address spender, uint256 amount) payable returns (bool)",
+            "function mint(address to, uint256 amount)",
+            "event Transfer(address indexed from, address to, uint256 amount)",
+            "event Approval(address indexed owner, address indexed spender, uint256 amount) anonymous",
+            "error InsufficientBalance(uint256 balance)",
+        ])
+        .unwrap();
+
+        let diff = diff_abi(&old, &new);
+
+        let changes = diff
+            .changes
+            .iter()
+            .map(|c| (c.breaking, c.kind.clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            changes,
+            vec![
+                (
+                    true,
+                    ChangeKind::MutabilityChanged {
+                        signature: "balanceOf(address)".to_owned(),
+                        old: StateMutability::View,
+                        new: StateMutability::Nonpayable
+                    }
+                ),
+                (
+                    true,
+                    ChangeKind::OutputsChanged {
+                        signature: "decimals()".to_owned(),
+                        old: vec!["uint8".to_owned()],
+                        new: vec!["uint256".to_owned()]
+                    }
+                ),
+                (
+                    false,
+                    ChangeKind::MutabilityChanged {
+                        signature: "decimals()".to_owned(),
+                        old: StateMutability::View,
+                        new: StateMutability::Pure
+                    }
+                ),
+                (
+                    true,
+                    ChangeKind::MutabilityChanged {
+                        signature: "deposit()".to_owned(),
+                        old: StateMutability::Payable,
+                        new: StateMutability::Nonpayable
+                    }
+                ),
+                (
+                    false,
+                    ChangeKind::MutabilityChanged {
+                        signature: "approve(address,uint256)".to_owned(),
+                        old: StateMutability::Nonpayable,
+                        new: StateMutability::Payable
+                    }
+                ),
+                (
+                    true,
+                    ChangeKind::IndexedChanged {
+                        signature: "Transfer(address,address,uint256)".to_owned(),
+                        old: vec![0, 1],
+                        new: vec![0]
+                    }
+                ),
+                (
+                    true,
+                    ChangeKind::AnonymousChanged {
+                        signature: "Approval(address,address,uint256)".to_owned(),
+                        anonymous: true
+                    }
+                ),
+                (
+                    false,
+                    ChangeKind::ErrorRemoved {
+                        signature: "Unauthorized(address)".to_owned()
+                    }
+                ),
+                (
+                    false,
+                    ChangeKind::FunctionAdded {
+                        signature: "mint(address,uint256)".to_owned()
+                    }
+                ),
+                (
+                    false,
+                    ChangeKind::ErrorAdded {
+                        signature: "InsufficientBalance(uint256)".to_owned()
+                    }
+                ),
+            ]
+        );
+
+        assert!(diff.is_breaking());
+        assert_eq!(diff.breaking().count(), 5);
+
+        let report = diff.to_string();
+
+        assert!(report.contains(
+            "[breaking] function `balanceOf(address)` state mutability changed from view to nonpayable"
+        ));
+        assert!(report.ends_with("10 changes, 5 breaking\n"));
+
+        // machine-readable form
+        let json = serde_json::to_value(&diff).unwrap();
+
+        assert_eq!(
+            json["changes"][0],
+            serde_json::json!({
+                "breaking": true,
+                "change": "mutabilityChanged",
+                "signature": "balanceOf(address)",
+                "old": "view",
+                "new": "nonpayable"
+            })
+        );
+
+        assert_eq!(serde_json::from_value::<diff::AbiDiff>(json).unwrap(), diff);
+
+        assert!(!diff_abi(&new, &new).is_breaking());
+        assert_eq!(diff_abi(&new, &new).to_string(), "no abi changes\n");
+    }
+
+    #[test]
+    fn test_diff_abi_removed_and_collision() {
+        use diff::ChangeKind;
+
+        let old = parse_human_readable(&[
+            "function burn(uint256 amount)",
+            "function pause()",
+            "event Paused(address account)",
+        ])
+        .unwrap();
+
+        // `collate_propagate_storage(bytes16)` shares selector 0x42966c68 with `burn(uint256)`
+        let new = parse_human_readable(&["function collate_propagate_storage(bytes16)"]).unwrap();
+
+        let diff = diff_abi(&old, &new);
+
+        let changes = diff
+            .changes
+            .iter()
+            .map(|c| (c.breaking, c.kind.clone()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            changes,
+            vec![
+                (
+                    true,
+                    ChangeKind::FunctionRemoved {
+                        signature: "burn(uint256)".to_owned()
+                    }
+                ),
+                (
+                    true,
+                    ChangeKind::FunctionRemoved {
+                        signature: "pause()".to_owned()
+                    }
+                ),
+                (
+                    true,
+                    ChangeKind::EventRemoved {
+                        signature: "Paused(address)".to_owned()
+                    }
+                ),
+                (
+                    false,
+                    ChangeKind::FunctionAdded {
+                        signature: "collate_propagate_storage(bytes16)".to_owned()
+                    }
+                ),
+                (
+                    true,
+                    ChangeKind::SelectorCollision {
+                        selector: "0x42966c68".to_owned(),
+                        signatures: vec![
+                            "collate_propagate_storage(bytes16)".to_owned(),
+                            "burn(uint256)".to_owned()
+                        ]
+                    }
+                ),
+            ]
+        );
+
+        // collisions only declared by the old abi are not reported
+        assert!(!diff_abi(&new, &[])
+            .changes
+            .iter()
+            .any(|c| matches!(c.kind, ChangeKind::SelectorCollision { .. })));
+    }
 
-        let caps = end_with_regex.captures("Hello[1][123]").unwrap();
+    fn arb_type() -> impl Strategy<Value = Type> {
+        let leaf = prop_oneof![
+            prop::sample::select(vec![
+                SimpleType::Address,
+                SimpleType::Uint,
+                SimpleType::Int,
+                SimpleType::Bool,
+                SimpleType::Fixed,
+                SimpleType::Ufixed,
+                SimpleType::Function,
+                SimpleType::Bytes,
+                SimpleType::String,
+                SimpleType::Tuple,
+            ])
+            .prop_map(Type::Simple),
+            (1..=32usize).prop_map(|m| Type::BytesM(BytesM { m })),
+            (any::<bool>(), 1..=32usize)
+                .prop_map(|(signed, m)| Type::IntegerM(IntegerM { signed, m: m * 8 })),
+            (any::<bool>(), 1..=32usize, 1..=80usize).prop_map(|(signed, m, n)| {
+                Type::FixedMN(FixedMN {
+                    signed,
+                    m: m * 8,
+                    n,
+                })
+            }),
+        ];
 
-        assert_eq!(&caps[1], "123");
+        leaf.prop_recursive(4, 16, 4, |element| {
+            prop_oneof![
+                prop::collection::vec(element.clone(), 0..4).prop_map(Type::Tuple),
+                element
+                    .clone()
+                    .prop_map(|element| Type::Array(Box::new(Array { element }))),
+                (element, 1..100_000usize)
+                    .prop_map(|(element, m)| Type::ArrayM(Box::new(ArrayM { element, m }))),
+            ]
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_type_encoding_rules(r#type in arb_type()) {
+            // `None` static size of static type means the size overflows
+            prop_assert!(!r#type.is_dynamic() || r#type.static_size().is_none());
+            prop_assert_eq!(r#type.head_size().unwrap_or_default() % WORD_SIZE, 0);
+            prop_assert!(r#type.alignment().is_some() || !r#type.is_value_type());
+            prop_assert!(r#type.is_hashed_topic() || !r#type.is_dynamic());
+
+            if let Type::Tuple(types) = &r#type {
+                prop_assert_eq!(r#type.is_dynamic(), types.iter().any(|c| c.is_dynamic()));
+            }
+        }
+
+        #[test]
+        fn test_type_round_trip(r#type in arb_type()) {
+            let data = r#type.to_string();
+
+            prop_assert_eq!(data.parse::<Type>().expect("Parse type"), r#type.clone());
+
+            let json = serde_json::to_string(&r#type).expect("Serialize type");
+
+            prop_assert_eq!(serde_json::from_str::<Type>(&json).expect("Deserialize type"), r#type);
+        }
     }
 
     #[test]
@@ -751,6 +1512,9 @@ mod tests {
             "tuple[][32]",
             "bool[20]",
             "uint256[20]",
+            "()",
+            "(uint256,address)[]",
+            "(uint256,(bool,bytes32)[2])[][3]",
         ];
 
         for v in test_vector {
@@ -764,6 +1528,75 @@ mod tests {
             serde_json::from_str(include_str!("abi.json")).expect("Parse hardhat artifact");
     }
 
+    #[test]
+    fn test_foundry_artifact() {
+        let artifact: FoundryArtifact =
+            serde_json::from_str(include_str!("../../rust/macros/tests/foundry.json"))
+                .expect("Parse foundry artifact");
+
+        assert_eq!(artifact.contract_name(), Some("Token"));
+
+        let natspec = artifact.natspec();
+
+        let contract = natspec.contract();
+
+        assert_eq!(contract.title.as_deref(), Some("A minimal token"));
+        assert_eq!(
+            contract.notice.as_deref(),
+            Some("Token used to test natspec support")
+        );
+
+        let balance_of = artifact
+            .abi
+            .iter()
+            .find_map(|c| match c {
+                AbiField::Function(function) if function.name == "balanceOf" => Some(function),
+                _ => None,
+            })
+            .expect("Find balanceOf");
+
+        let doc = natspec.function(balance_of);
+
+        assert_eq!(
+            doc.notice.as_deref(),
+            Some("Returns the token balance of `owner`")
+        );
+        assert_eq!(
+            doc.params.get("owner").map(|c| c.as_str()),
+            Some("The queried account")
+        );
+        assert_eq!(doc.return_doc("", 0), Some("The balance of `owner`"));
+
+        assert_eq!(
+            natspec
+                .constructor()
+                .params
+                .get("supply_")
+                .map(|c| c.as_str()),
+            Some("Initial supply minted to the deployer")
+        );
+    }
+
+    #[test]
+    fn test_selector() {
+        let function: Function = serde_json::from_str(
+            r#"{"name":"transfer","inputs":[{"name":"to","type":"address"},{"name":"value","type":"uint256"}],"stateMutability":"nonpayable"}"#,
+        )
+        .expect("Parse function");
+
+        assert_eq!(to_hex(function.selector()), "0xa9059cbb");
+
+        let event: Event = serde_json::from_str(
+            r#"{"name":"Transfer","inputs":[{"name":"from","type":"address","indexed":true},{"name":"to","type":"address","indexed":true},{"name":"value","type":"uint256"}],"anonymous":false}"#,
+        )
+        .expect("Parse event");
+
+        assert_eq!(
+            to_hex(event.topic()),
+            "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+        );
+    }
+
     #[test]
     fn test_field() {
         let data = r#"
@@ -782,4 +1615,122 @@ mod tests {
 
         _ = serde_json::from_str::<AbiField>(data).expect("Parse abi field");
     }
+
+    #[test]
+    fn test_human_readable() {
+        let fields = parse_human_readable(&[
+            "constructor(string memory name_, uint8 decimals) payable",
+            "function balanceOf(address owner) external view returns (uint256)",
+            "function transfer(address payable to, uint256 value) returns (bool)",
+            "function submit((uint256 id, address[] signers)[2] calldata orders, tuple(bytes32,bytes) extra)",
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "error InsufficientBalance(uint256 available, uint256 required)",
+            "receive() external payable",
+            "fallback() external",
+        ])
+        .expect("Parse human-readable abi");
+
+        assert_eq!(fields.len(), 8);
+
+        match &fields[0] {
+            AbiField::Constructor(constructor) => {
+                assert_eq!(constructor.signature(), "Constructor(string,uint8)");
+                assert_eq!(constructor.inputs[0].name, "name_");
+                assert!(matches!(
+                    constructor.state_mutability,
+                    StateMutability::Payable
+                ));
+            }
+            field => panic!("Expect constructor, found {:?}", field),
+        }
+
+        match &fields[1] {
+            AbiField::Function(function) => {
+                assert_eq!(function.signature(), "balanceOf(address)");
+                assert_eq!(function.selector(), [0x70, 0xa0, 0x82, 0x31]);
+                assert!(matches!(function.state_mutability, StateMutability::View));
+                assert_eq!(function.outputs.len(), 1);
+                assert_eq!(function.outputs[0].name, "");
+            }
+            field => panic!("Expect function, found {:?}", field),
+        }
+
+        match &fields[3] {
+            AbiField::Function(function) => {
+                assert_eq!(
+                    function.signature(),
+                    "submit((uint256,address[])[2],(bytes32,bytes))"
+                );
+                assert_eq!(
+                    function.inputs[0].components.as_ref().unwrap()[1].name,
+                    "signers"
+                );
+            }
+            field => panic!("Expect function, found {:?}", field),
+        }
+
+        match &fields[4] {
+            AbiField::Event(event) => {
+                assert_eq!(event.signature(), "Transfer(address,address,uint256)");
+                assert!(event.inputs[0].indexed);
+                assert!(!event.inputs[2].indexed);
+            }
+            field => panic!("Expect event, found {:?}", field),
+        }
+
+        match &fields[5] {
+            AbiField::Error(error) => {
+                assert_eq!(error.signature(), "InsufficientBalance(uint256,uint256)");
+            }
+            field => panic!("Expect error, found {:?}", field),
+        }
+    }
+
+    #[test]
+    fn test_human_readable_aliases() {
+        let fields = parse_human_readable(&[
+            "function transfer(address to, uint amount)",
+            "event E(int x, fixed y, (ufixed, uint[2])[] z)",
+        ])
+        .expect("Parse human-readable abi");
+
+        match &fields[0] {
+            AbiField::Function(function) => {
+                assert_eq!(function.signature(), "transfer(address,uint256)");
+                assert_eq!(to_hex(function.selector()), "0xa9059cbb");
+                assert_eq!(function.inputs[1].r#type.to_string(), "uint");
+            }
+            field => panic!("Expect function, found {:?}", field),
+        }
+
+        match &fields[1] {
+            AbiField::Event(event) => assert_eq!(
+                event.signature(),
+                "E(int256,fixed128x18,(ufixed128x18,uint256[2])[])"
+            ),
+            field => panic!("Expect event, found {:?}", field),
+        }
+    }
+
+    #[test]
+    fn test_human_readable_error() {
+        for signature in [
+            "balanceOf(address) view returns (uint256)",
+            "function balanceOf(address",
+            "function transfer(address to, uint256 value) returns bool",
+            "function approve(address spender, uint257 value)",
+            "event Transfer(address indexed from) view",
+            "function withdraw(uint256 amount) external;",
+        ] {
+            let err = signature
+                .parse::<AbiField>()
+                .expect_err("Parse invalid signature");
+
+            assert!(
+                matches!(&err, AbiError::HumanReadable(s, _) if s == signature),
+                "{}",
+                err
+            );
+        }
+    }
 }
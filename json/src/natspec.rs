@@ -0,0 +1,182 @@
+//! Solidity [`NatSpec`](https://docs.soliditylang.org/en/v0.8.17/natspec-format.html) documentation support,
+//! produced by solc as `userdoc`/`devdoc` contract output.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Event, Function};
+
+/// User documentation, contains `@notice` tags
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDoc {
+    /// Contract `@notice`
+    pub notice: Option<String>,
+    /// Functions documentation keyed by canonical signature, the constructor is keyed by `constructor`
+    #[serde(default)]
+    pub methods: HashMap<String, UserDocItem>,
+    /// Events documentation keyed by canonical signature
+    #[serde(default)]
+    pub events: HashMap<String, UserDocItem>,
+    /// Errors documentation keyed by canonical signature
+    #[serde(default)]
+    pub errors: HashMap<String, Vec<UserDocItem>>,
+}
+
+/// User documentation of contract item
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDocItem {
+    /// `@notice` tag
+    pub notice: Option<String>,
+}
+
+/// Developer documentation, contains `@title`, `@author`, `@dev`, `@param` and `@return` tags
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DevDoc {
+    /// Contract `@title`
+    pub title: Option<String>,
+    /// Contract `@author`
+    pub author: Option<String>,
+    /// Contract `@dev`
+    pub details: Option<String>,
+    /// Functions documentation keyed by canonical signature, the constructor is keyed by `constructor`
+    #[serde(default)]
+    pub methods: HashMap<String, DevDocItem>,
+    /// Events documentation keyed by canonical signature
+    #[serde(default)]
+    pub events: HashMap<String, DevDocItem>,
+    /// Errors documentation keyed by canonical signature
+    #[serde(default)]
+    pub errors: HashMap<String, Vec<DevDocItem>>,
+}
+
+/// Developer documentation of contract item
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DevDocItem {
+    /// `@dev` tag
+    pub details: Option<String>,
+    /// `@param` tags keyed by parameter name
+    #[serde(default)]
+    pub params: HashMap<String, String>,
+    /// `@return` tags keyed by return variable name, unnamed variables are keyed by `_<index>`
+    #[serde(default)]
+    pub returns: HashMap<String, String>,
+}
+
+/// Contract `NatSpec` documentation, merged from `userdoc` and `devdoc`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NatSpec {
+    #[serde(default)]
+    pub userdoc: UserDoc,
+    #[serde(default)]
+    pub devdoc: DevDoc,
+}
+
+/// Merged documentation of one contract item
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Doc {
+    /// `@title` tag, only meaningful for contract
+    pub title: Option<String>,
+    /// `@notice` tag
+    pub notice: Option<String>,
+    /// `@dev` tag
+    pub details: Option<String>,
+    /// `@param` tags keyed by parameter name
+    pub params: HashMap<String, String>,
+    /// `@return` tags keyed by return variable name, unnamed variables are keyed by `_<index>`
+    pub returns: HashMap<String, String>,
+}
+
+impl Doc {
+    /// Returns true if this doc doesn't contain any tag
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.notice.is_none()
+            && self.details.is_none()
+            && self.params.is_empty()
+            && self.returns.is_empty()
+    }
+
+    /// Returns `@return` doc of output parameter `name` at `index`
+    pub fn return_doc(&self, name: &str, index: usize) -> Option<&str> {
+        if name.is_empty() {
+            self.returns.get(&format!("_{}", index))
+        } else {
+            self.returns.get(name)
+        }
+        .map(|c| c.as_str())
+    }
+
+    fn merge(user: Option<&UserDocItem>, dev: Option<&DevDocItem>) -> Self {
+        Self {
+            title: None,
+            notice: user.and_then(|c| c.notice.clone()),
+            details: dev.and_then(|c| c.details.clone()),
+            params: dev.map(|c| c.params.clone()).unwrap_or_default(),
+            returns: dev.map(|c| c.returns.clone()).unwrap_or_default(),
+        }
+    }
+}
+
+impl NatSpec {
+    /// Create `NatSpec` from `userdoc` and `devdoc`
+    pub fn new(userdoc: Option<UserDoc>, devdoc: Option<DevDoc>) -> Self {
+        Self {
+            userdoc: userdoc.unwrap_or_default(),
+            devdoc: devdoc.unwrap_or_default(),
+        }
+    }
+
+    /// Contract level documentation
+    pub fn contract(&self) -> Doc {
+        Doc {
+            title: self.devdoc.title.clone(),
+            notice: self.userdoc.notice.clone(),
+            details: self.devdoc.details.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Constructor documentation
+    pub fn constructor(&self) -> Doc {
+        Doc::merge(
+            self.userdoc.methods.get("constructor"),
+            self.devdoc.methods.get("constructor"),
+        )
+    }
+
+    /// Function documentation
+    pub fn function(&self, function: &Function) -> Doc {
+        let signature = function.signature();
+
+        Doc::merge(
+            self.userdoc.methods.get(&signature),
+            self.devdoc.methods.get(&signature),
+        )
+    }
+
+    /// Event documentation
+    pub fn event(&self, event: &Event) -> Doc {
+        let signature = event.signature();
+
+        Doc::merge(
+            self.userdoc.events.get(&signature),
+            self.devdoc.events.get(&signature),
+        )
+    }
+
+    /// Error documentation, solc may output more than one doc for the same error signature, the first one is returned.
+    pub fn error(&self, error: &Error) -> Doc {
+        let signature = error.signature();
+
+        Doc::merge(
+            self.userdoc.errors.get(&signature).and_then(|c| c.first()),
+            self.devdoc.errors.get(&signature).and_then(|c| c.first()),
+        )
+    }
+}
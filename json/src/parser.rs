@@ -0,0 +1,249 @@
+//! Recursive-descent parser of abi type declarations, e.g: `uint256`, `bytes32[2][]`.
+//!
+//! ```text
+//! type       := ( elementary | tuple ) ( '[' digits? ']' )*
+//! tuple      := 'tuple'? '(' ( type ( ',' type )* )? ')'
+//! elementary := address | bool | string | bytes | function | tuple
+//!             | uint<M>? | int<M>? | bytes<M> | ufixed(<M>x<N>)? | fixed(<M>x<N>)?
+//! ```
+
+use crate::{AbiError, Array, ArrayM, BytesM, FixedMN, IntegerM, SimpleType, Type};
+
+/// Parse type declaration `data`, the error variant indicates which kind of type is invalid.
+pub(crate) fn parse_type(data: &str) -> Result<Type, AbiError> {
+    let mut parser = Parser { data, pos: 0 };
+
+    let r#type = parser.parse_type()?;
+
+    match parser.peek() {
+        None => Ok(r#type),
+        Some(c) => Err(AbiError::UnknownType(format!(
+            "{}, unexpected `{}` at offset {}",
+            data, c, parser.pos
+        ))),
+    }
+}
+
+struct Parser<'a> {
+    data: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.data[self.pos..].chars().next()
+    }
+
+    fn next_if(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume the longest prefix of chars matching `f`.
+    fn take_while<F: Fn(char) -> bool>(&mut self, f: F) -> &'a str {
+        let start = self.pos;
+
+        while let Some(c) = self.peek().filter(|c| f(*c)) {
+            self.pos += c.len_utf8();
+        }
+
+        &self.data[start..self.pos]
+    }
+
+    fn parse_type(&mut self) -> Result<Type, AbiError> {
+        let mut r#type = if self.peek() == Some('(') {
+            self.parse_tuple()?
+        } else {
+            self.parse_elementary()?
+        };
+
+        while self.next_if('[') {
+            r#type = self.parse_array(r#type)?;
+        }
+
+        Ok(r#type)
+    }
+
+    /// Parse tuple components, e.g: `(address,uint256[])`
+    fn parse_tuple(&mut self) -> Result<Type, AbiError> {
+        self.next_if('(');
+
+        let mut types = vec![];
+
+        if self.next_if(')') {
+            return Ok(Type::Tuple(types));
+        }
+
+        loop {
+            types.push(self.parse_type()?);
+
+            if self.next_if(')') {
+                return Ok(Type::Tuple(types));
+            }
+
+            if !self.next_if(',') {
+                return Err(AbiError::Tuple(
+                    self.data.to_owned(),
+                    format!("expect `,` or `)` at offset {}", self.pos),
+                ));
+            }
+        }
+    }
+
+    fn parse_elementary(&mut self) -> Result<Type, AbiError> {
+        let name = self.take_while(|c| c.is_ascii_alphanumeric());
+
+        if name == "tuple" && self.peek() == Some('(') {
+            return self.parse_tuple();
+        }
+
+        let simple = match name {
+            "address" => Some(SimpleType::Address),
+            "bool" => Some(SimpleType::Bool),
+            "string" => Some(SimpleType::String),
+            "bytes" => Some(SimpleType::Bytes),
+            "function" => Some(SimpleType::Function),
+            "tuple" => Some(SimpleType::Tuple),
+            "uint" => Some(SimpleType::Uint),
+            "int" => Some(SimpleType::Int),
+            "fixed" => Some(SimpleType::Fixed),
+            "ufixed" => Some(SimpleType::Ufixed),
+            _ => None,
+        };
+
+        if let Some(simple) = simple {
+            return Ok(Type::Simple(simple));
+        }
+
+        if let Some(m) = name.strip_prefix("bytes").filter(|c| is_digits(c)) {
+            return self.parse_bytes_m(m).map(Type::BytesM);
+        }
+
+        if let Some(m) = name.strip_prefix("uint").filter(|c| is_digits(c)) {
+            return self.parse_integer_m(false, m).map(Type::IntegerM);
+        }
+
+        if let Some(m) = name.strip_prefix("int").filter(|c| is_digits(c)) {
+            return self.parse_integer_m(true, m).map(Type::IntegerM);
+        }
+
+        if let Some(m_n) = name.strip_prefix("ufixed") {
+            return self.parse_fixed_m_n(false, m_n).map(Type::FixedMN);
+        }
+
+        if let Some(m_n) = name.strip_prefix("fixed") {
+            return self.parse_fixed_m_n(true, m_n).map(Type::FixedMN);
+        }
+
+        if name.is_empty() {
+            Err(AbiError::UnknownType(format!(
+                "{}, expect type name at offset {}",
+                self.data, self.pos
+            )))
+        } else {
+            Err(AbiError::UnknownType(self.data.to_owned()))
+        }
+    }
+
+    fn parse_bytes_m(&self, m: &str) -> Result<BytesM, AbiError> {
+        match parse_decimal(m) {
+            Some(m) if (1..=32).contains(&m) => Ok(BytesM { m }),
+            _ => Err(AbiError::BytesM(
+                self.data.to_owned(),
+                "0 < M <= 32".to_string(),
+            )),
+        }
+    }
+
+    fn parse_integer_m(&self, signed: bool, m: &str) -> Result<IntegerM, AbiError> {
+        match parse_decimal(m) {
+            Some(m) if is_bits(m) => Ok(IntegerM { signed, m }),
+            _ => Err(AbiError::IntegerM(
+                self.data.to_owned(),
+                "M bits must meet the condition 0 < M <= 256, M % 8 == 0".to_string(),
+            )),
+        }
+    }
+
+    fn parse_fixed_m_n(&self, signed: bool, m_n: &str) -> Result<FixedMN, AbiError> {
+        let Some((m, n)) = m_n
+            .split_once('x')
+            .filter(|(m, n)| is_digits(m) && is_digits(n))
+        else {
+            return Err(AbiError::FixedMN(
+                self.data.to_owned(),
+                "{u}fixed<M>x<N>: fixed-point decimal number of M bits, 8 <= M <= 256, M % 8 == 0, and 0 < N <= 80"
+                    .to_string(),
+            ));
+        };
+
+        let m = match parse_decimal(m) {
+            Some(m) if is_bits(m) => m,
+            _ => {
+                return Err(AbiError::FixedMN(
+                    self.data.to_owned(),
+                    "M bits must meet the condition 0 < M <= 256, M % 8 == 0".to_string(),
+                ))
+            }
+        };
+
+        let n = match parse_decimal(n) {
+            Some(n) if (1..=80).contains(&n) => n,
+            _ => {
+                return Err(AbiError::FixedMN(
+                    self.data.to_owned(),
+                    "decimal numbers N must meet the condition 0 < N <= 80".to_string(),
+                ))
+            }
+        };
+
+        Ok(FixedMN { m, n, signed })
+    }
+
+    /// Parse array suffix after `[`, `element` is the type declared before the suffix.
+    fn parse_array(&mut self, element: Type) -> Result<Type, AbiError> {
+        let m = self.take_while(|c| c != ']' && c != '[');
+
+        if !self.next_if(']') {
+            return Err(AbiError::Array(
+                self.data.to_owned(),
+                format!("expect `]` at offset {}", self.pos),
+            ));
+        }
+
+        if m.is_empty() {
+            return Ok(Type::Array(Box::new(Array { element })));
+        }
+
+        match parse_decimal(m) {
+            Some(len) if len > 0 => Ok(Type::ArrayM(Box::new(ArrayM { element, m: len }))),
+            _ => Err(AbiError::ArrayM(
+                self.data.to_owned(),
+                format!("invalid array length `{}`", m),
+            )),
+        }
+    }
+}
+
+/// Returns true if `value` is non-empty decimal digits.
+fn is_digits(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|c| c.is_ascii_digit())
+}
+
+/// Parse decimal digits without leading zeros, e.g: `256` but not `0256`.
+fn parse_decimal(value: &str) -> Option<usize> {
+    if !is_digits(value) || (value.len() > 1 && value.starts_with('0')) {
+        return None;
+    }
+
+    value.parse().ok()
+}
+
+/// Returns true if `m` is valid bits of integer or fixed-point type, 0 < M <= 256, M % 8 == 0.
+fn is_bits(m: usize) -> bool {
+    (8..=256).contains(&m) && m.is_multiple_of(8)
+}
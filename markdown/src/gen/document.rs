@@ -0,0 +1,135 @@
+use ethbind_gen::{Contract, File};
+use ethbind_json::NatSpec;
+
+use super::{to_anchor, to_doc_text};
+
+#[derive(Debug, Default)]
+pub(crate) struct DocumentGenerator {
+    pub(crate) contract_name: String,
+    pub(crate) natspec: NatSpec,
+    constructor: Option<String>,
+    /// Function summary table rows
+    function_rows: Vec<String>,
+    functions: Vec<String>,
+    events: Vec<String>,
+    errors: Vec<String>,
+}
+
+impl DocumentGenerator {
+    pub(crate) fn new(contract_name: &str) -> Self {
+        Self {
+            contract_name: contract_name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn file_name(&self) -> String {
+        format!("{}.md", self.contract_name)
+    }
+
+    pub(crate) fn set_constructor(&mut self, section: String) {
+        self.constructor = Some(section);
+    }
+
+    pub(crate) fn add_function(
+        &mut self,
+        signature: &str,
+        selector: &str,
+        mutability: &str,
+        section: String,
+    ) {
+        self.function_rows.push(format!(
+            "| [`{}`](#{}) | `{}` | `{}` |",
+            signature,
+            to_anchor(signature),
+            selector,
+            mutability
+        ));
+
+        self.functions.push(section);
+    }
+
+    pub(crate) fn add_event(&mut self, section: String) {
+        self.events.push(section);
+    }
+
+    pub(crate) fn add_error(&mut self, section: String) {
+        self.errors.push(section);
+    }
+
+    /// Returns index page table row of this contract.
+    pub(crate) fn to_index_row(&self) -> String {
+        format!(
+            "| [{}]({}) | {} | {} | {} |",
+            self.contract_name,
+            self.file_name(),
+            self.functions.len(),
+            self.events.len(),
+            self.errors.len()
+        )
+    }
+
+    pub(crate) fn finalize(&self) -> anyhow::Result<Contract> {
+        let mut data = format!("# {}\n", self.contract_name);
+
+        let doc = self.natspec.contract();
+
+        if let Some(title) = &doc.title {
+            data.push_str(&format!("\n**{}**\n", title));
+        }
+
+        if let Some(author) = &self.natspec.devdoc.author {
+            data.push_str(&format!("\n- Author: {}\n", author));
+        }
+
+        let text = to_doc_text(&doc);
+
+        if !text.is_empty() {
+            data.push('\n');
+            data.push_str(text.trim_end());
+            data.push('\n');
+        }
+
+        if let Some(constructor) = &self.constructor {
+            data.push_str("\n## Constructor\n\n");
+            data.push_str(constructor);
+        }
+
+        if !self.functions.is_empty() {
+            data.push_str("\n## Functions\n\n");
+            data.push_str("| Function | Selector | Mutability |\n| --- | --- | --- |\n");
+            data.push_str(&self.function_rows.join("\n"));
+            data.push('\n');
+
+            for section in &self.functions {
+                data.push('\n');
+                data.push_str(section);
+            }
+        }
+
+        if !self.events.is_empty() {
+            data.push_str("\n## Events\n");
+
+            for section in &self.events {
+                data.push('\n');
+                data.push_str(section);
+            }
+        }
+
+        if !self.errors.is_empty() {
+            data.push_str("\n## Errors\n");
+
+            for section in &self.errors {
+                data.push('\n');
+                data.push_str(section);
+            }
+        }
+
+        Ok(Contract {
+            files: vec![File {
+                name: self.file_name(),
+                data,
+            }],
+        })
+    }
+}
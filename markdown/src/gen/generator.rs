@@ -0,0 +1,179 @@
+use ethbind_gen::{Contract, File, Generator};
+use ethbind_json::*;
+
+use crate::MarkdownGenerator;
+
+use super::{to_doc_text, to_param_name, to_params_table};
+
+impl Generator for MarkdownGenerator {
+    fn begin<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        name: &str,
+    ) -> anyhow::Result<()> {
+        self.new_document(name);
+
+        Ok(())
+    }
+
+    fn generate_natspec<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        natspec: &NatSpec,
+    ) -> anyhow::Result<()> {
+        self.current_document().natspec = natspec.clone();
+
+        Ok(())
+    }
+
+    fn uses_runtime_types(&self) -> bool {
+        false
+    }
+
+    fn end<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        _name: &str,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn finalize<R: ethbind_gen::RuntimeBinder>(
+        self,
+        _runtime_binder: &mut R,
+    ) -> anyhow::Result<Vec<Contract>> {
+        let mut contracts = vec![];
+
+        let mut index =
+            "# Contracts\n\n| Contract | Functions | Events | Errors |\n| --- | --- | --- | --- |\n"
+                .to_owned();
+
+        for c in &self.documents {
+            contracts.push(c.finalize()?);
+
+            index.push_str(&c.to_index_row());
+            index.push('\n');
+        }
+
+        // The index page across all bound contracts.
+        contracts.push(Contract {
+            files: vec![File {
+                name: "index.md".to_owned(),
+                data: index,
+            }],
+        });
+
+        Ok(contracts)
+    }
+
+    fn generate_deploy<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        contructor: &Constructor,
+        _deploy_bytes: &str,
+    ) -> anyhow::Result<()> {
+        let doc = self.current_document().natspec.constructor();
+
+        let section = format!(
+            "{}- Mutability: `{}`\n\n{}",
+            to_doc_text(&doc),
+            contructor.state_mutability,
+            to_params_table(&contructor.inputs, false, &doc.params)
+        );
+
+        self.current_document().set_constructor(section);
+
+        Ok(())
+    }
+
+    fn generate_error<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        error: &Error,
+    ) -> anyhow::Result<()> {
+        log::trace!("generate error {}", error.name);
+
+        let doc = self.current_document().natspec.error(error);
+
+        let section = format!(
+            "### {}\n\n{}- Selector: `{}`\n\n{}",
+            error.signature(),
+            to_doc_text(&doc),
+            to_hex(error.selector()),
+            to_params_table(&error.inputs, false, &doc.params)
+        );
+
+        self.current_document().add_error(section);
+
+        Ok(())
+    }
+
+    fn generate_event<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        event: &Event,
+    ) -> anyhow::Result<()> {
+        log::trace!("generate event {}", event.name);
+
+        let topic = if event.anonymous {
+            "- Anonymous: `true`, no signature topic".to_owned()
+        } else {
+            format!("- Topic: `{}`", to_hex(event.topic()))
+        };
+
+        let doc = self.current_document().natspec.event(event);
+
+        let section = format!(
+            "### {}\n\n{}{}\n\n{}",
+            event.signature(),
+            to_doc_text(&doc),
+            topic,
+            to_params_table(&event.inputs, true, &doc.params)
+        );
+
+        self.current_document().add_event(section);
+
+        Ok(())
+    }
+
+    fn generate_fn<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        function: &Function,
+    ) -> anyhow::Result<()> {
+        log::trace!("generate fn {}", function.name);
+
+        let signature = function.signature();
+
+        let selector = to_hex(function.selector());
+
+        let mutability = function.state_mutability.to_string();
+
+        let doc = self.current_document().natspec.function(function);
+
+        let returns = function
+            .outputs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, param)| {
+                doc.return_doc(&param.name, index)
+                    .map(|text| (to_param_name(param, index), text.to_owned()))
+            })
+            .collect();
+
+        let section = format!(
+            "### {}\n\n{}- Selector: `{}`\n- Mutability: `{}`\n\n**Inputs**\n\n{}\n**Outputs**\n\n{}",
+            signature,
+            to_doc_text(&doc),
+            selector,
+            mutability,
+            to_params_table(&function.inputs, false, &doc.params),
+            to_params_table(&function.outputs, false, &returns)
+        );
+
+        self.current_document()
+            .add_function(&signature, &selector, &mutability, section);
+
+        Ok(())
+    }
+}
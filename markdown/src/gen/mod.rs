@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use ethbind_json::{Doc, Parameter};
+
+/// The markdown api documentation generator for `Ethbind`
+///
+/// Generates one markdown file per contract and an `index.md` page across all bound contracts,
+/// the [`RuntimeBinder`](ethbind_gen::RuntimeBinder) parameters are ignored.
+#[derive(Debug, Default)]
+pub struct MarkdownGenerator {
+    documents: Vec<DocumentGenerator>,
+}
+
+impl MarkdownGenerator {
+    /// Push new document generator to back end of generation list
+    pub(crate) fn new_document(&mut self, name: &str) {
+        self.documents.push(DocumentGenerator::new(name))
+    }
+
+    /// Returns document generator at back edn of generation list.
+    pub(crate) fn current_document(&mut self) -> &mut DocumentGenerator {
+        self.documents.last_mut().expect("Call new_document first")
+    }
+}
+
+/// Convert heading text to github style anchor, e.g: `balanceOf(address)` => `balanceofaddress`
+pub(crate) fn to_anchor(heading: &str) -> String {
+    heading
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Format parameter type cell, e.g: `` `(address,uint256)` (`struct IMaker.Metadata`) ``
+fn to_type_cell(param: &Parameter) -> String {
+    let canonical_type = param.canonical_type();
+
+    match &param.internal_type {
+        Some(internal_type) if *internal_type != canonical_type => {
+            format!("`{}` (`{}`)", canonical_type, internal_type)
+        }
+        _ => format!("`{}`", canonical_type),
+    }
+}
+
+/// Format `NatSpec` notice/details paragraphs, returns empty string if both are missing.
+pub(crate) fn to_doc_text(doc: &Doc) -> String {
+    [&doc.notice, &doc.details]
+        .into_iter()
+        .flatten()
+        .map(|c| format!("{}\n\n", c))
+        .collect()
+}
+
+/// Escape text in a table cell, `|` would split the cell and line breaks would end the row.
+pub(crate) fn to_table_cell(text: &str) -> String {
+    text.trim()
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+/// Returns parameter display name, unnamed parameters are named by their `index`.
+pub(crate) fn to_param_name(param: &Parameter, index: usize) -> String {
+    if param.name.is_empty() {
+        index.to_string()
+    } else {
+        param.name.clone()
+    }
+}
+
+/// Flatten parameters to table rows, tuple components are listed with dotted names, e.g: `maker_.sku`
+///
+/// If `indexed` is true, the rows contains the `Indexed` column, which is empty for tuple components.
+/// If `docs` is not `None`, the rows contains the `Description` column.
+fn to_rows(
+    params: &[Parameter],
+    prefix: &str,
+    indexed: bool,
+    docs: Option<&HashMap<String, String>>,
+    rows: &mut Vec<String>,
+) {
+    for (index, param) in params.iter().enumerate() {
+        let name = format!("{}{}", prefix, to_param_name(param, index));
+
+        let mut row = format!("| `{}` | {} |", name, to_type_cell(param));
+
+        if indexed {
+            match (prefix.is_empty(), param.indexed) {
+                (false, _) => row.push_str("  |"),
+                (true, true) => row.push_str(" yes |"),
+                (true, false) => row.push_str(" no |"),
+            }
+        }
+
+        if let Some(docs) = docs {
+            // `NatSpec` only documents top level parameters
+            match docs.get(&name) {
+                Some(doc) if prefix.is_empty() => {
+                    row.push_str(&format!(" {} |", to_table_cell(doc)))
+                }
+                _ => row.push_str("  |"),
+            }
+        }
+
+        rows.push(row);
+
+        if let Some(components) = &param.components {
+            to_rows(components, &format!("{}.", name), indexed, docs, rows);
+        }
+    }
+}
+
+/// Generate markdown parameters table, returns `None.` if `params` is empty.
+///
+/// The `Description` column is added only if `docs`, which is keyed by [`to_param_name`], documents at least one of `params`.
+pub(crate) fn to_params_table(
+    params: &[Parameter],
+    indexed: bool,
+    docs: &HashMap<String, String>,
+) -> String {
+    if params.is_empty() {
+        return "None.\n".to_owned();
+    }
+
+    let docs = if params
+        .iter()
+        .enumerate()
+        .any(|(index, param)| docs.contains_key(&to_param_name(param, index)))
+    {
+        Some(docs)
+    } else {
+        None
+    };
+
+    let mut header = vec!["Name", "Type"];
+
+    if indexed {
+        header.push("Indexed");
+    }
+
+    if docs.is_some() {
+        header.push("Description");
+    }
+
+    let mut rows = vec![
+        format!("| {} |", header.join(" | ")),
+        format!("|{}", " --- |".repeat(header.len())),
+    ];
+
+    to_rows(params, "", indexed, docs, &mut rows);
+
+    format!("{}\n", rows.join("\n"))
+}
+
+mod generator;
+
+mod document;
+use document::*;
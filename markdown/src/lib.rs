@@ -0,0 +1,108 @@
+mod gen;
+pub use gen::*;
+
+pub type BindingBuilder = ethbind_gen::BindingBuilder<
+    ethbind_gen::Executor<MarkdownGenerator, ethbind_gen::JsonRuntimeBinder>,
+>;
+
+pub use ethbind_gen::*;
+pub use ethbind_json::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gen() {
+        _ = pretty_env_logger::try_init();
+
+        let contracts = BindingBuilder::new((MarkdownGenerator::default(), JsonRuntimeBinder::default()))
+            .bind_hardhat(include_str!("../../rust/macros/tests/abi.json"))
+            .bind(
+                "Token",
+                r#"[
+                    {"type":"function","name":"transfer","inputs":[{"name":"to","type":"address"},{"name":"value","type":"uint256"}],"outputs":[{"name":"","type":"bool"}],"stateMutability":"nonpayable"},
+                    {"type":"error","name":"InsufficientBalance","inputs":[{"name":"available","type":"uint256"},{"name":"required","type":"uint256"}]}
+                ]"#,
+            )
+            .finalize()
+            .expect("Generate data");
+
+        assert_eq!(contracts.len(), 3);
+
+        let wallet = &contracts[0].files[0];
+
+        assert_eq!(wallet.name, "PersonalWallet.md");
+
+        assert!(wallet.data.starts_with("# PersonalWallet\n\n## Constructor\n\n- Mutability: `nonpayable`\n\n| Name | Type |\n| --- | --- |\n| `WETH_` | `address` |\n"));
+
+        assert!(wallet
+            .data
+            .contains("| `maker_` | `(address,uint256,address,uint256,uint128,uint128)` (`struct IMaker.Metadata`) |\n| `maker_.sku` | `address` |\n"));
+
+        assert!(wallet.data.contains("### OwnershipTransferred(address,address)\n\n- Topic: `0x8be0079c531659141344cd1fd0a4f28419497f9722a3daafe3b4186f6b6457e0`\n\n| Name | Type | Indexed |\n| --- | --- | --- |\n| `previousOwner` | `address` | yes |\n"));
+
+        let token = &contracts[1].files[0];
+
+        assert!(token.data.contains(
+            "| [`transfer(address,uint256)`](#transferaddressuint256) | `0xa9059cbb` | `nonpayable` |"
+        ));
+
+        assert!(token
+            .data
+            .contains("### InsufficientBalance(uint256,uint256)\n\n- Selector: `0xcf479181`\n"));
+
+        let index = &contracts[2].files[0];
+
+        assert_eq!(index.name, "index.md");
+
+        assert!(index.data.contains("| [Token](Token.md) | 1 | 0 | 1 |"));
+    }
+
+    #[test]
+    fn test_natspec() {
+        let contracts =
+            BindingBuilder::new((MarkdownGenerator::default(), JsonRuntimeBinder::default()))
+                .bind_foundry(include_str!("../../rust/macros/tests/foundry.json"))
+                .finalize()
+                .expect("Generate data");
+
+        let token = &contracts[0].files[0];
+
+        assert_eq!(token.name, "Token.md");
+
+        assert!(token.data.starts_with("# Token\n\n**A minimal token**\n\n- Author: ethbind\n\nToken used to test natspec support\n\nOnly used by ethbind tests\n\n## Constructor\n"));
+
+        assert!(token.data.contains("### transfer(address,uint256)\n\nTransfers `value` tokens to `to`\n\nReverts with `InsufficientBalance` if the sender balance is too low\n\n- Selector: `0xa9059cbb`\n"));
+
+        assert!(token.data.contains("| Name | Type | Description |\n| --- | --- | --- |\n| `to` | `address` | The recipient |\n"));
+
+        assert!(token
+            .data
+            .contains("| `0` | `uint256` | The balance of `owner` |\n"));
+
+        assert!(token
+            .data
+            .contains("| `value` | `uint256` | no | The transferred amount |\n"));
+    }
+
+    #[test]
+    fn test_table_cell() {
+        let params: Vec<Parameter> = serde_json::from_str(
+            r#"[{ "name": "mode", "type": "uint8" }, { "name": "data", "type": "bytes" }]"#,
+        )
+        .expect("Parse parameters");
+
+        let docs = [
+            ("mode".to_owned(), "0 | 1\nor 2".to_owned()),
+            ("data".to_owned(), "Payload\r\n".to_owned()),
+        ]
+        .into_iter()
+        .collect();
+
+        let table = gen::to_params_table(&params, false, &docs);
+
+        assert!(table.contains("| `mode` | `uint8` | 0 \\| 1<br>or 2 |\n"));
+        assert!(table.contains("| `data` | `bytes` | Payload |\n"));
+    }
+}
@@ -1,74 +1,772 @@
 use std::{env, fs::read_to_string, path::PathBuf};
 
-use ethbind_rust::{BindingBuilder, JsonRuntimeBinder, RustGenerator, ToTokenStream};
+use ethbind_rust::{
+    is_mapping_key, AbiField, ArtifactFormat, BindError, BindingBuilder, CallMode, ContractConfig,
+    ContractOptions, JsonRuntimeBinder, ModuleLayout, RustGenerator, ToTokenStream,
+};
 use proc_macro::TokenStream;
-use proc_macro2::Ident;
+use proc_macro2::Span;
 use quote::quote;
-use syn::{parse::Parse, parse_macro_input, LitStr, Token};
+use syn::{
+    braced, bracketed,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    token, Ident, LitBool, LitStr, Token,
+};
 
+/// Options of the named arguments form
+const OPTIONS: &[&str] = &[
+    "name",
+    "mapping",
+    "abi",
+    "abis",
+    "module",
+    "format",
+    "derives",
+    "skip",
+    "sync",
+    "call_mode",
+    "client_trait",
+    "call_builder",
+];
+
+/// Mutually exclusive options of the named arguments form
+const EXCLUSIVE_OPTIONS: &[(&str, &str)] =
+    &[("sync", "call_mode"), ("abi", "abis"), ("name", "abis")];
+
+/// Type mapping or abi argument
+enum Source {
+    /// File path relative to `CARGO_MANIFEST_DIR`
+    Path(LitStr),
+    /// Inline json, e.g: `r#"[{"type": "function", ...}]"#`
+    Json(LitStr),
+    /// Human-readable abi, e.g: `["function balanceOf(address) view returns (uint256)"]`
+    Signatures(Span, Vec<LitStr>),
+    /// Inline type mapping, e.g: `{ address: "Address", uint_m: "U<$m>" }`
+    Object(Span, Vec<(String, LitStr)>),
+}
+
+impl Parse for Source {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(token::Bracket) {
+            let content;
+
+            let bracket = bracketed!(content in input);
+
+            let signatures = content.parse_terminated::<_, Token!(,)>(<LitStr as Parse>::parse)?;
+
+            return Ok(Self::Signatures(
+                bracket.span,
+                signatures.into_iter().collect(),
+            ));
+        }
+
+        if input.peek(token::Brace) {
+            let content;
+
+            let brace = braced!(content in input);
+
+            let mut pairs = vec![];
+
+            while !content.is_empty() {
+                let key = if content.peek(LitStr) {
+                    content.parse::<LitStr>()?.value()
+                } else {
+                    content.call(Ident::parse_any)?.to_string()
+                };
+
+                content.parse::<Token!(:)>()?;
+
+                pairs.push((key, content.parse()?));
+
+                if content.is_empty() {
+                    break;
+                }
+
+                content.parse::<Token!(,)>()?;
+            }
+
+            return Ok(Self::Object(brace.span, pairs));
+        }
+
+        Ok(Self::from_lit(input.parse()?))
+    }
+}
+
+impl Source {
+    /// Literal starting with `{` or `[` is inline json, otherwise file path.
+    fn from_lit(lit: LitStr) -> Self {
+        if lit.value().trim_start().starts_with(['{', '[']) {
+            Self::Json(lit)
+        } else {
+            Self::Path(lit)
+        }
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Self::Path(lit) | Self::Json(lit) => lit.span(),
+            Self::Signatures(span, _) | Self::Object(span, _) => *span,
+        }
+    }
+
+    fn is_inline(&self) -> bool {
+        !matches!(self, Self::Path(_))
+    }
+
+    /// Returns compile error pointing at this argument, the message is prefixed with the file path.
+    fn to_syn_error<E: std::fmt::Display>(&self, err: E) -> syn::Error {
+        match self {
+            Self::Path(path) => {
+                syn::Error::new(path.span(), format!("{}: {:#}", path.value(), err))
+            }
+            _ => syn::Error::new(self.span(), format!("{:#}", err)),
+        }
+    }
+
+    /// Load the json data, returns the resolved path if the data is read from file.
+    fn load(&self) -> syn::Result<(Option<PathBuf>, String)> {
+        match self {
+            Self::Path(path) => {
+                let (resolved, data) = load_json_file(path)?;
+
+                Ok((Some(resolved), data))
+            }
+            Self::Json(lit) => Ok((None, lit.value())),
+            Self::Signatures(_, signatures) => {
+                let fields = signatures
+                    .iter()
+                    .map(|c| {
+                        c.value()
+                            .parse::<AbiField>()
+                            .map_err(|err| syn::Error::new(c.span(), err))
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+
+                let data = serde_json::to_string(&fields).map_err(|err| self.to_syn_error(err))?;
+
+                Ok((None, data))
+            }
+            Self::Object(_, pairs) => {
+                let object = pairs
+                    .iter()
+                    .map(|(key, value)| (key.clone(), serde_json::Value::String(value.value())))
+                    .collect::<serde_json::Map<_, _>>();
+
+                Ok((None, serde_json::Value::Object(object).to_string()))
+            }
+        }
+    }
+
+    /// Load abi artifacts, file path containing `*`, `?` or `[` is expanded as glob pattern.
+    fn load_abis(&self) -> syn::Result<Vec<AbiArtifact<'_>>> {
+        let pattern = match self {
+            Self::Path(path) if path.value().contains(['*', '?', '[']) => path,
+            Self::Path(path) => {
+                let (resolved, data) = load_json_file(path)?;
+
+                return Ok(vec![AbiArtifact {
+                    source: self,
+                    label: Some(path.value()),
+                    path: Some(resolved),
+                    data,
+                }]);
+            }
+            _ => {
+                let (_, data) = self.load()?;
+
+                return Ok(vec![AbiArtifact {
+                    source: self,
+                    label: None,
+                    path: None,
+                    data,
+                }]);
+            }
+        };
+
+        let to_syn_error =
+            |err| syn::Error::new(pattern.span(), format!("{}: {}", pattern.value(), err));
+
+        let dir = env::var("CARGO_MANIFEST_DIR").map_err(|err| to_syn_error(err.to_string()))?;
+
+        let dir = PathBuf::from(dir);
+
+        let mut paths = glob::glob(&dir.join(pattern.value()).to_string_lossy())
+            .map_err(|err| to_syn_error(err.to_string()))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| to_syn_error(err.to_string()))?;
+
+        if paths.is_empty() {
+            return Err(to_syn_error("No file matches the pattern".to_owned()));
+        }
+
+        paths.sort();
+
+        paths
+            .into_iter()
+            .map(|path| {
+                let label = path
+                    .strip_prefix(&dir)
+                    .unwrap_or(&path)
+                    .display()
+                    .to_string();
+
+                let data = read_to_string(&path).map_err(|err| {
+                    syn::Error::new(pattern.span(), format!("{}: {}", label, err))
+                })?;
+
+                Ok(AbiArtifact {
+                    source: self,
+                    label: Some(label),
+                    path: Some(path),
+                    data,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Abi artifact loaded from `abi` or `abis` argument
+struct AbiArtifact<'a> {
+    source: &'a Source,
+    /// File path shown in error messages, e.g: the file matched by glob pattern
+    label: Option<String>,
+    /// Resolved file path
+    path: Option<PathBuf>,
+    data: String,
+}
+
+impl AbiArtifact<'_> {
+    /// Returns compile error pointing at the source argument, the message is prefixed with the file path.
+    fn to_syn_error<E: std::fmt::Display>(&self, err: E) -> syn::Error {
+        match &self.label {
+            Some(label) => syn::Error::new(self.source.span(), format!("{}: {:#}", label, err)),
+            None => syn::Error::new(self.source.span(), format!("{:#}", err)),
+        }
+    }
+}
+
+/// `contract!` arguments, declared by the positional form `[Name,] "mapping.json", "abi.json"`
+/// or the named form `name = Name, mapping = "mapping.json", abi = "abi.json", ...`.
+///
+/// Type mapping and abi can also be declared inline, see [`Source`].
+/// Several artifacts can be bound together by `abis = ["a.json", "artifacts/*.json"]`, so they share generated types.
 struct Contract {
     pub contract_name: Option<String>,
-    pub type_mapping: String,
-    pub abi_data: String,
+    pub type_mapping: Source,
+    pub abis: Vec<Source>,
+    /// Span of the `abi` or `abis` argument
+    pub abis_span: Span,
+    pub module: Option<Ident>,
+    pub format: Option<ArtifactFormat>,
+    pub derives: Vec<String>,
+    pub skip: Vec<String>,
+    pub call_mode: CallMode,
+    pub client_trait: bool,
+    pub call_builder: bool,
+}
+
+impl Contract {
+    fn new(
+        contract_name: Option<String>,
+        type_mapping: Source,
+        abis: Vec<Source>,
+        abis_span: Span,
+    ) -> Self {
+        Self {
+            contract_name,
+            type_mapping,
+            abis,
+            abis_span,
+            module: None,
+            format: None,
+            derives: vec![],
+            skip: vec![],
+            call_mode: CallMode::default(),
+            client_trait: false,
+            call_builder: false,
+        }
+    }
+
+    fn parse_named(input: ParseStream) -> syn::Result<Self> {
+        let mut contract_name = None;
+        let mut type_mapping = None;
+        let mut abis = None;
+        let mut module = None;
+        let mut format = None;
+        let mut derives = vec![];
+        let mut skip = vec![];
+        let mut call_mode = CallMode::default();
+        let mut client_trait = false;
+        let mut call_builder = false;
+
+        let mut keys: Vec<String> = vec![];
+
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+
+            let name = key.to_string();
+
+            if keys.contains(&name) {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("Duplicate option `{}`", key),
+                ));
+            }
+
+            for (a, b) in EXCLUSIVE_OPTIONS {
+                if (name == *a && keys.iter().any(|c| c == b))
+                    || (name == *b && keys.iter().any(|c| c == a))
+                {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("Options `{}` and `{}` are exclusive", a, b),
+                    ));
+                }
+            }
+
+            input.parse::<Token!(=)>()?;
+
+            match name.as_str() {
+                "name" => contract_name = Some(input.parse::<Ident>()?.to_string()),
+                "mapping" => type_mapping = Some(input.parse()?),
+                "abi" => {
+                    let abi: Source = input.parse()?;
+
+                    abis = Some((vec![abi], key.span()));
+                }
+                "abis" => {
+                    let sources = parse_list::<LitStr>(input)?
+                        .into_iter()
+                        .map(Source::from_lit)
+                        .collect();
+
+                    abis = Some((sources, key.span()));
+                }
+                "module" => module = Some(input.parse()?),
+                "format" => format = Some(parse_format(input)?),
+                "derives" => {
+                    derives = parse_list::<syn::Path>(input)?
+                        .iter()
+                        .map(|c| quote!(#c).to_string())
+                        .collect()
+                }
+                "skip" => {
+                    skip = parse_list::<LitStr>(input)?
+                        .iter()
+                        .map(|c| c.value())
+                        .collect()
+                }
+                "sync" => {
+                    if input.parse::<LitBool>()?.value {
+                        call_mode = CallMode::Blocking;
+                    }
+                }
+                "call_mode" => call_mode = parse_call_mode(input)?,
+                "client_trait" => client_trait = input.parse::<LitBool>()?.value,
+                "call_builder" => call_builder = input.parse::<LitBool>()?.value,
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "Unknown option `{}`, expected one of: {}",
+                            key,
+                            OPTIONS.join(", ")
+                        ),
+                    ))
+                }
+            }
+
+            keys.push(name);
+
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<Token!(,)>()?;
+        }
+
+        let missing =
+            |name| syn::Error::new(Span::call_site(), format!("Missing option `{}`", name));
+
+        let (abis, abis_span) = abis.ok_or_else(|| missing("abi"))?;
+
+        Ok(Self {
+            module,
+            format,
+            derives,
+            skip,
+            call_mode,
+            client_trait,
+            call_builder,
+            ..Self::new(
+                contract_name,
+                type_mapping.ok_or_else(|| missing("mapping"))?,
+                abis,
+                abis_span,
+            )
+        })
+    }
 }
 
 impl Parse for Contract {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Ident) && input.peek2(Token!(=)) {
+            return Self::parse_named(input);
+        }
+
         let contract_name: Option<Ident> = input.parse()?;
 
         if contract_name.is_some() {
             input.parse::<Token!(,)>()?;
         }
 
-        let type_mapping: LitStr = input.parse()?;
+        let type_mapping: Source = input.parse()?;
 
         input.parse::<Token!(,)>()?;
 
-        let abi_data: LitStr = input.parse()?;
+        let abi_data: Source = input.parse()?;
 
-        Ok(Self {
-            contract_name: contract_name.map(|c| c.to_string()),
-            type_mapping: type_mapping.value(),
-            abi_data: abi_data.value(),
-        })
+        let abis_span = abi_data.span();
+
+        Ok(Self::new(
+            contract_name.map(|c| c.to_string()),
+            type_mapping,
+            vec![abi_data],
+            abis_span,
+        ))
     }
 }
 
-mod contract {
-    syn::custom_keyword!(hardhat);
+/// Parse bracketed list, e.g: `[Debug, Clone]`
+fn parse_list<T: Parse>(input: ParseStream) -> syn::Result<Punctuated<T, Token!(,)>> {
+    let content;
+
+    bracketed!(content in input);
+
+    content.parse_terminated(T::parse)
+}
+
+/// Parse `abi`, `hardhat` or `foundry`
+fn parse_format(input: ParseStream) -> syn::Result<ArtifactFormat> {
+    let format: Ident = input.parse()?;
+
+    match format.to_string().as_str() {
+        "abi" => Ok(ArtifactFormat::Abi),
+        "hardhat" => Ok(ArtifactFormat::Hardhat),
+        "foundry" => Ok(ArtifactFormat::Foundry),
+        _ => Err(syn::Error::new(
+            format.span(),
+            "Expect artifact format `abi`, `hardhat` or `foundry`",
+        )),
+    }
 }
 
-fn load_json_file(path: &str) -> String {
-    let dir = env::var("CARGO_MANIFEST_DIR").expect("Find CARGO_MANIFEST_DIR");
+/// Parse `async`, `blocking` or `both`
+fn parse_call_mode(input: ParseStream) -> syn::Result<CallMode> {
+    if input.peek(Token!(async)) {
+        input.parse::<Token!(async)>()?;
+
+        return Ok(CallMode::Async);
+    }
 
-    let path = PathBuf::from(dir).join(path);
+    let call_mode: Ident = input.parse()?;
 
-    read_to_string(path.clone()).expect(&format!("Read json file: {:?}", path))
+    match call_mode.to_string().as_str() {
+        "blocking" => Ok(CallMode::Blocking),
+        "both" => Ok(CallMode::Both),
+        _ => Err(syn::Error::new(
+            call_mode.span(),
+            "Expect call mode `async`, `blocking` or `both`",
+        )),
+    }
+}
+
+/// Returns true if `err` is caused by runtime types mapping rather than the contract abi.
+fn is_mapping_error(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<BindError>(),
+        Some(
+            BindError::UnknownType(_)
+                | BindError::MissingKeys(_)
+                | BindError::UnknownPreset(_)
+                | BindError::InvalidRuntimeType(_)
+        )
+    )
+}
+
+/// Read file `path`, which is relative to `CARGO_MANIFEST_DIR`, returns the resolved path and file content.
+fn load_json_file(path: &LitStr) -> syn::Result<(PathBuf, String)> {
+    let to_syn_error = |err| syn::Error::new(path.span(), format!("{}: {}", path.value(), err));
+
+    let dir = env::var("CARGO_MANIFEST_DIR").map_err(|err| to_syn_error(err.to_string()))?;
+
+    let resolved = PathBuf::from(dir).join(path.value());
+
+    let data = read_to_string(&resolved).map_err(|err| to_syn_error(err.to_string()))?;
+
+    Ok((resolved, data))
+}
+
+/// Emit `include_bytes!` of files read by macro expansion, so cargo rebuilds the bindings if they are changed.
+fn track_files(paths: &[PathBuf]) -> proc_macro2::TokenStream {
+    let paths = paths.iter().map(|c| c.to_string_lossy());
+
+    quote! {
+        #(const _: &[u8] = include_bytes!(#paths);)*
+    }
+}
+
+/// Bind `artifacts` by one [`BindingBuilder`], so the structs used by several contracts are generated once.
+fn bind_artifacts(
+    contract: &Contract,
+    type_mapping: &str,
+    artifacts: &[&AbiArtifact],
+) -> anyhow::Result<Vec<ethbind_rust::Contract>> {
+    let type_mapping: JsonRuntimeBinder = type_mapping.parse()?;
+
+    let mut generator = RustGenerator::new(ModuleLayout::Inline).call_mode(contract.call_mode);
+
+    if contract.client_trait {
+        generator = generator.client_trait();
+    }
+
+    if contract.call_builder {
+        generator = generator.call_builder();
+    }
+
+    let mut builder = BindingBuilder::new((generator, type_mapping));
+
+    for artifact in artifacts {
+        let format = match artifact.source {
+            Source::Signatures(..) => Some(ArtifactFormat::Abi),
+            _ => contract.format,
+        };
+
+        // The artifact format is detected if not specified, raw abi file is named by file stem by default.
+        let config = ContractConfig {
+            path: artifact.path.clone().unwrap_or_default(),
+            format,
+            rename: contract.contract_name.clone(),
+            skip: contract.skip.clone(),
+            options: ContractOptions {
+                derives: contract.derives.clone(),
+            },
+            ..Default::default()
+        };
+
+        builder = builder.bind_config_data(artifact.data.clone(), config);
+    }
+
+    builder.finalize()
+}
+
+/// Generate the bindings of `contract`, returns the file tracking items and the generated items.
+///
+/// `overrides` replace the type mapping entries of the same keys, e.g: `("address", "H160")`.
+fn generate(
+    contract: &Contract,
+    overrides: &[(String, String)],
+) -> syn::Result<(proc_macro2::TokenStream, Vec<proc_macro2::TokenStream>)> {
+    if let Source::Signatures(span, _) = &contract.type_mapping {
+        return Err(syn::Error::new(
+            *span,
+            "Expect type mapping file path, inline json or object",
+        ));
+    }
+
+    for abi in &contract.abis {
+        if let Source::Object(span, _) = abi {
+            return Err(syn::Error::new(
+                *span,
+                "Expect abi file path, inline json or human-readable signatures",
+            ));
+        }
+    }
+
+    let (mapping_path, mut type_mapping) = contract.type_mapping.load()?;
+
+    if !overrides.is_empty() {
+        if let Ok(serde_json::Value::Object(mut object)) = serde_json::from_str(&type_mapping) {
+            for (key, value) in overrides {
+                object.insert(key.clone(), serde_json::Value::String(value.clone()));
+            }
+
+            type_mapping = serde_json::Value::Object(object).to_string();
+        }
+    }
+
+    // Report invalid mapping before loading the artifacts.
+    type_mapping
+        .parse::<JsonRuntimeBinder>()
+        .map_err(|err| contract.type_mapping.to_syn_error(err))?;
+
+    let mut artifacts = vec![];
+
+    for abi in &contract.abis {
+        artifacts.append(&mut abi.load_abis()?);
+    }
+
+    // Inline raw abi has no file stem to fall back on.
+    for artifact in &artifacts {
+        let format = match artifact.source {
+            Source::Signatures(..) => Some(ArtifactFormat::Abi),
+            _ => contract.format,
+        };
+
+        if artifact.source.is_inline()
+            && contract.contract_name.is_none()
+            && format.or_else(|| ArtifactFormat::detect(&artifact.data).ok())
+                == Some(ArtifactFormat::Abi)
+        {
+            return Err(syn::Error::new(
+                artifact.source.span(),
+                "Option `name` is required by inline abi",
+            ));
+        }
+    }
+
+    let contracts = bind_artifacts(
+        contract,
+        &type_mapping,
+        &artifacts.iter().collect::<Vec<_>>(),
+    )
+    .map_err(|err| {
+        if is_mapping_error(&err) {
+            return contract.type_mapping.to_syn_error(err);
+        }
+
+        // Bind the artifacts one by one to find out which one is failed.
+        artifacts
+            .iter()
+            .find_map(|artifact| {
+                bind_artifacts(contract, &type_mapping, &[artifact])
+                    .err()
+                    .map(|err| artifact.to_syn_error(err))
+            })
+            .unwrap_or_else(|| syn::Error::new(contract.abis_span, format!("{:#}", err)))
+    })?;
+
+    let contracts = contracts
+        .to_token_streams()
+        .map_err(|err| syn::Error::new(contract.abis_span, format!("{:#}", err)))?;
+
+    let tracked = track_files(
+        &std::iter::once(mapping_path)
+            .chain(artifacts.iter().map(|c| c.path.clone()))
+            .flatten()
+            .collect::<Vec<_>>(),
+    );
+
+    Ok((tracked, contracts))
+}
+
+fn bind_contract(contract: Contract) -> syn::Result<proc_macro2::TokenStream> {
+    let (tracked, contracts) = generate(&contract, &[])?;
+
+    match &contract.module {
+        Some(module) => Ok(quote! {
+            #tracked
+
+            pub mod #module {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #(#contracts)*
+            }
+        }),
+        None => Ok(quote!(#tracked #(#contracts)*)),
+    }
 }
 
 #[proc_macro]
 pub fn contract(item: TokenStream) -> TokenStream {
     let contract = parse_macro_input!(item as Contract);
 
-    let type_mapping: JsonRuntimeBinder = load_json_file(&contract.type_mapping)
-        .parse()
-        .expect("Parse mapping data");
-
-    let abi_data = load_json_file(&contract.abi_data);
-
-    let generator = if let Some(contract_name) = contract.contract_name {
-        BindingBuilder::new((RustGenerator::default(), type_mapping))
-            .bind(&contract_name, abi_data)
-            .finalize()
-            .expect("Generate contract/abi binding code")
-    } else {
-        BindingBuilder::new((RustGenerator::default(), type_mapping))
-            .bind_hardhat(abi_data)
-            .finalize()
-            .expect("Generate contract/abi binding code")
+    bind_contract(contract)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Returns true if the type alias is a type mapping override, which is named by mapping key, e.g: `type address = H160;`
+fn is_mapping_override(alias: &syn::ItemType) -> bool {
+    alias.generics.params.is_empty() && is_mapping_key(&alias.ident.to_string())
+}
+
+fn bind_module(contract: Contract, module: syn::ItemMod) -> syn::Result<proc_macro2::TokenStream> {
+    if let Some(ident) = &contract.module {
+        return Err(syn::Error::new(
+            ident.span(),
+            "Option `module` isn't supported by `#[bind]`, the items are generated into the annotated module",
+        ));
+    }
+
+    let syn::ItemMod {
+        attrs,
+        vis,
+        mod_token,
+        ident,
+        content,
+        semi,
+    } = module;
+
+    let Some((_, items)) = content else {
+        return Err(syn::Error::new(
+            semi.map(|c| c.span).unwrap_or_else(|| ident.span()),
+            "Expect inline module, e.g: `mod erc20 {}`",
+        ));
     };
 
-    let contracts = generator.to_token_streams().expect("To token streams");
+    let mut overrides = vec![];
+    let mut kept = vec![];
+
+    for item in items {
+        match item {
+            syn::Item::Type(alias) if is_mapping_override(&alias) => {
+                let ty = &alias.ty;
+
+                overrides.push((alias.ident.to_string(), quote!(#ty).to_string()));
+            }
+            item => kept.push(item),
+        }
+    }
+
+    let (tracked, contracts) = generate(&contract, &overrides)?;
+
+    let (inner_attrs, outer_attrs): (Vec<_>, Vec<_>) = attrs
+        .into_iter()
+        .partition(|c| matches!(c.style, syn::AttrStyle::Inner(_)));
+
+    Ok(quote! {
+        #(#outer_attrs)*
+        #vis #mod_token #ident {
+            #(#inner_attrs)*
+
+            #[allow(unused_imports)]
+            use super::*;
+
+            #tracked
+
+            #(#kept)*
+
+            #(#contracts)*
+        }
+    })
+}
+
+/// Generate the bindings into the annotated inline module, which accepts the same arguments as [`contract!`] except `module`.
+///
+/// Hand-written items of the module are kept. Type alias named by mapping key, e.g: `type address = H160;` or `type rt_error = MyError;`,
+/// overrides the type mapping entry instead of being emitted, other type aliases are kept.
+#[proc_macro_attribute]
+pub fn bind(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let contract = parse_macro_input!(attr as Contract);
+
+    let module = parse_macro_input!(item as syn::ItemMod);
 
-    quote!(#(#contracts)*).into()
+    bind_module(contract, module)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
 }
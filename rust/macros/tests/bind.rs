@@ -0,0 +1,58 @@
+use ethbind_rust::mock::{BlockingMockClient, CallKind};
+use ethbind_rust_macros::bind;
+
+mod common;
+use common::mock;
+
+#[bind(
+    name = Erc20,
+    mapping = "tests/mapping_blocking.json",
+    abi = [
+        "function balanceOf(address owner) external view returns (uint256)",
+        "function transfer(address to, uint256 value) external returns (bool)",
+    ],
+    sync = true,
+)]
+mod erc20 {
+    // Overrides the `address` type mapping.
+    type address = mock::Account;
+
+    // Not a mapping key, kept as a module item.
+    #[allow(non_camel_case_types)]
+    pub type helper_t = u8;
+
+    pub const DECIMALS: helper_t = 18;
+
+    impl Erc20 {
+        pub fn at(
+            client: ethbind_rust::mock::BlockingMockClient<mock::Ops>,
+            address: mock::Account,
+        ) -> Self {
+            Self { client, address }
+        }
+    }
+}
+
+#[test]
+fn test_bind_module() {
+    let client = BlockingMockClient::<mock::Ops>::new();
+
+    client.stub("balanceOf(address)", mock::Int::<false, 256>(100));
+
+    let token = erc20::Erc20::at(client.clone(), mock::Account(1));
+
+    assert_eq!(
+        token.balance_of(mock::Account(2)).expect("Balance of"),
+        mock::Int(100)
+    );
+
+    assert_eq!(erc20::DECIMALS, 18);
+
+    let decimals: erc20::helper_t = erc20::DECIMALS;
+
+    assert_eq!(decimals, 18u8);
+
+    let kinds = client.calls().iter().map(|c| c.kind).collect::<Vec<_>>();
+
+    assert_eq!(kinds, [CallKind::Call]);
+}
@@ -0,0 +1,28 @@
+//! Mock runtime types of `mapping.json` and `mapping_blocking.json`, shared by the binding tests.
+
+#![allow(unused)]
+
+pub mod mock {
+    pub use serde::{Deserialize, Serialize};
+
+    #[derive(Default)]
+    pub struct Ops;
+
+    #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct Address(pub u8);
+
+    /// Replaces `Address` by the `type address = ..` override of `#[bind]` modules.
+    #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct Account(pub u8);
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct TransactionReceipt {
+        pub status: bool,
+    }
+
+    #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct Int<const SIGN: bool, const LEN: usize>(pub u64);
+
+    #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct Fixed<const SIGN: bool, const M: usize, const N: usize>(pub u64);
+}
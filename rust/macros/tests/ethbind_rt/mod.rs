@@ -0,0 +1,53 @@
+//! Stand-in of the runtime glue module referenced by bundled presets.
+
+#![allow(unused)]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Default)]
+pub struct Ops;
+
+pub struct Client;
+
+impl Client {
+    pub async fn deploy_contract<A: Default>(
+        &self,
+        contract_name: &str,
+        encoder: String,
+        deploy_data: &str,
+        ops: Ops,
+    ) -> anyhow::Result<A> {
+        Ok(Default::default())
+    }
+
+    pub async fn eth_call<A>(
+        &self,
+        method_name: &str,
+        address: &A,
+        encoder: String,
+    ) -> anyhow::Result<String> {
+        Ok(Default::default())
+    }
+
+    pub async fn send_raw_transaction<A, R: Default>(
+        &self,
+        method_name: &str,
+        address: &A,
+        encoder: String,
+        ops: Ops,
+    ) -> anyhow::Result<R> {
+        Ok(Default::default())
+    }
+}
+
+pub fn abi_encode<T: Serialize>(value: T) -> anyhow::Result<String> {
+    Err(anyhow::format_err!(
+        "abi encoding is not supported by the stand-in runtime"
+    ))
+}
+
+pub fn abi_decode<'de, T: Deserialize<'de>>(data: String) -> anyhow::Result<T> {
+    Err(anyhow::format_err!(
+        "abi decoding is not supported by the stand-in runtime"
+    ))
+}
@@ -60,5 +60,7 @@ mod mock {
 
 contract!("tests/mapping.json", "tests/abi.json");
 
+contract!("tests/mapping.json", "tests/foundry.json");
+
 #[test]
 fn test_gen() {}
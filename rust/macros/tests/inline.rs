@@ -0,0 +1,88 @@
+use ethbind_rust::mock::{BlockingMockClient, CallKind};
+use ethbind_rust_macros::contract;
+
+mod common;
+use common::mock;
+
+contract!(
+    name = Erc20,
+    mapping = {
+        address: "mock::Address",
+        bool: "bool",
+        uint_m: "mock::Int<false,$m>",
+        rt_client: "ethbind_rust::mock::BlockingMockClient<mock::Ops>",
+        rt_error: "anyhow::Error",
+        rt_opts: "mock::Ops",
+        rt_receipt: "mock::TransactionReceipt",
+        rt_serialize_derive: "mock::Serialize",
+        rt_deserialize_derive: "mock::Deserialize",
+        rt_abi_serialize: "ethbind_rust::mock::abi_encode",
+        rt_abi_deserialize: "ethbind_rust::mock::abi_decode",
+    },
+    abi = [
+        "function balanceOf(address owner) external view returns (uint256)",
+        "function transfer(address to, uint256 value) external returns (bool)",
+        "event Transfer(address indexed from, address indexed to, uint256 value)",
+    ],
+    sync = true,
+);
+
+contract!(
+    name = Ownable,
+    mapping = "tests/mapping_blocking.json",
+    abi = r#"[{
+        "type": "function",
+        "name": "owner",
+        "inputs": [],
+        "outputs": [{ "name": "", "type": "address" }],
+        "stateMutability": "view"
+    }]"#,
+    sync = true,
+);
+
+#[test]
+fn test_inline_signatures() {
+    let client = BlockingMockClient::<mock::Ops>::new();
+
+    client.stub("balanceOf(address)", mock::Int::<false, 256>(100));
+
+    let token = Erc20 {
+        client: client.clone(),
+        address: mock::Address(1),
+    };
+
+    assert_eq!(
+        token.balance_of(mock::Address(2)).expect("Balance of"),
+        mock::Int(100)
+    );
+
+    token
+        .transfer(mock::Address(2), mock::Int(1))
+        .expect("Transfer");
+
+    let event = Erc20Transfer {
+        from: mock::Address(1),
+        to: mock::Address(2),
+        value: mock::Int(3),
+    };
+
+    assert_eq!(event.value, mock::Int(3));
+
+    let kinds = client.calls().iter().map(|c| c.kind).collect::<Vec<_>>();
+
+    assert_eq!(kinds, [CallKind::Call, CallKind::Transaction]);
+}
+
+#[test]
+fn test_inline_json() {
+    let client = BlockingMockClient::<mock::Ops>::new();
+
+    client.stub("owner()", mock::Address(3));
+
+    let ownable = Ownable {
+        client,
+        address: mock::Address(1),
+    };
+
+    assert_eq!(ownable.owner().expect("Owner"), mock::Address(3));
+}
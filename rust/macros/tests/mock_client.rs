@@ -0,0 +1,93 @@
+use ethbind_rust::mock::{block_on, CallKind, MockClient, MockError};
+use ethbind_rust_macros::contract;
+
+mod common;
+use common::mock;
+
+contract!("tests/mapping_mock.json", "tests/foundry.json");
+
+#[test]
+fn test_mock_client() {
+    let client = MockClient::<mock::Ops>::new();
+
+    client
+        .stub("Constructor(uint256)", mock::Address(1))
+        .stub("balanceOf(address)", mock::Int::<false, 256>(0))
+        .stub_with("0x70a08231", mock::Address(2), mock::Int::<false, 256>(100));
+
+    let token = block_on(Token::deploy_with(
+        client.clone(),
+        mock::Int::<false, 256>(1000),
+        mock::Ops,
+    ))
+    .expect("Deploy");
+
+    assert_eq!(token.address, mock::Address(1));
+
+    let balance = block_on(token.balance_of(mock::Address(2))).expect("Balance of");
+
+    assert_eq!(balance, mock::Int(100));
+
+    let balance = block_on(token.balance_of(mock::Address(3))).expect("Balance of");
+
+    assert_eq!(balance, mock::Int(0));
+
+    let calls = client.calls_to("balanceOf(address)");
+
+    assert_eq!(calls.len(), 2);
+    assert_eq!(calls[0].kind, CallKind::Call);
+    assert_eq!(calls[0].address, serde_json::json!(1));
+    assert_eq!(calls[1].inputs, serde_json::json!(3));
+    assert_eq!(client.calls()[0].kind, CallKind::Deploy);
+}
+
+#[test]
+fn test_mock_revert() {
+    let client = MockClient::<mock::Ops>::new();
+
+    let error = TokenError::InsufficientBalance {
+        available: mock::Int(1),
+        required: mock::Int(2),
+    };
+
+    client.revert("transfer(address,uint256)", error.signature(), &error);
+
+    let token = Token {
+        client: client.clone(),
+        address: mock::Address(1),
+    };
+
+    let err = block_on(token.transfer(mock::Address(2), mock::Int::<false, 256>(2)))
+        .expect_err("Reverted");
+
+    let Some(MockError::Revert(revert)) = err.downcast_ref::<MockError>() else {
+        panic!("Expect revert, got {}", err);
+    };
+
+    assert_eq!(revert.signature, "InsufficientBalance(uint256,uint256)");
+
+    assert!(matches!(
+        revert.decode::<TokenError>().expect("Decode revert"),
+        TokenError::InsufficientBalance { .. }
+    ));
+
+    client.stub_with(
+        "transfer(address,uint256)",
+        (mock::Address(3), mock::Int::<false, 256>(1)),
+        mock::TransactionReceipt { status: true },
+    );
+
+    let receipt =
+        block_on(token.transfer(mock::Address(3), mock::Int::<false, 256>(1))).expect("Transfer");
+
+    assert!(receipt.status);
+
+    assert!(block_on(
+        Token {
+            client: MockClient::<mock::Ops>::new(),
+            address: mock::Address(1),
+        }
+        .balance_of(mock::Address(2))
+    )
+    .is_err());
+}
@@ -0,0 +1,47 @@
+use ethbind_rust::mock::{BlockingMockClient, CallKind};
+use ethbind_rust_macros::contract;
+
+mod common;
+use common::mock;
+
+contract!(
+    mapping = "tests/mapping_blocking.json",
+    abis = ["tests/shared/*.json"],
+    module = exchange,
+    sync = true,
+);
+
+#[test]
+fn test_shared_types() {
+    let client = BlockingMockClient::<mock::Ops>::new();
+
+    let order = || exchange::Order {
+        maker: mock::Address(2),
+        amount: mock::Int(100),
+    };
+
+    client.stub("orders(address)", vec![order()]);
+
+    let maker = exchange::Maker {
+        client: client.clone(),
+        address: mock::Address(1),
+    };
+
+    // Both contracts use the same `Order` struct.
+    let taker = exchange::Taker {
+        client: client.clone(),
+        address: mock::Address(1),
+    };
+
+    maker.place(order()).expect("Place");
+
+    let orders = taker.orders(mock::Address(2)).expect("Orders");
+
+    assert_eq!(orders.len(), 1);
+    assert_eq!(orders[0].maker, mock::Address(2));
+    assert_eq!(orders[0].amount, mock::Int(100));
+
+    let kinds = client.calls().iter().map(|c| c.kind).collect::<Vec<_>>();
+
+    assert_eq!(kinds, [CallKind::Transaction, CallKind::Call]);
+}
@@ -0,0 +1,53 @@
+use ethbind_rust::mock::{BlockingMockClient, CallKind};
+use ethbind_rust_macros::contract;
+
+mod common;
+use common::mock;
+
+contract!(
+    name = Erc20,
+    mapping = "tests/mapping_blocking.json",
+    abi = "tests/foundry.json",
+    format = foundry,
+    derives = [Debug, Clone, PartialEq],
+    skip = ["transfer(address,uint256)"],
+    sync = true,
+);
+
+#[test]
+fn test_named_options() {
+    let client = BlockingMockClient::<mock::Ops>::new();
+
+    client
+        .stub("Constructor(uint256)", mock::Address(1))
+        .stub("balanceOf(address)", mock::Int::<false, 256>(100));
+
+    let token = Erc20::deploy_with(client.clone(), mock::Int::<false, 256>(1000), mock::Ops)
+        .expect("Deploy");
+
+    assert_eq!(token.address, mock::Address(1));
+
+    assert_eq!(
+        token.balance_of(mock::Address(2)).expect("Balance of"),
+        mock::Int(100)
+    );
+
+    let error = Erc20Error::InsufficientBalance {
+        available: mock::Int(1),
+        required: mock::Int(2),
+    };
+
+    assert_eq!(error.clone(), error);
+
+    let event = Erc20Transfer {
+        from: mock::Address(1),
+        to: mock::Address(2),
+        value: mock::Int(3),
+    };
+
+    assert_eq!(format!("{:?}", event.to), "Address(2)");
+
+    let kinds = client.calls().iter().map(|c| c.kind).collect::<Vec<_>>();
+
+    assert_eq!(kinds, [CallKind::Deploy, CallKind::Call]);
+}
@@ -0,0 +1,67 @@
+use ethbind_rust::mock::block_on;
+use ethbind_rust_macros::contract;
+
+mod ethbind_rt;
+
+/// Stand-in of `alloy` 1.x with the same type paths.
+#[allow(unused)]
+mod alloy {
+    pub mod primitives {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        pub struct Address;
+
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        pub struct Bytes;
+
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        pub struct FixedBytes<const N: usize>;
+
+        pub type Function = FixedBytes<24>;
+
+        pub mod aliases {
+            use serde::{Deserialize, Serialize};
+
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct U256;
+
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct I256;
+        }
+    }
+
+    pub mod rpc {
+        pub mod types {
+            use serde::{Deserialize, Serialize};
+
+            #[derive(Debug, Default, Serialize, Deserialize)]
+            pub struct TransactionReceipt;
+        }
+    }
+}
+
+contract!("tests/presets/alloy-v1.json", "tests/abi.json");
+
+contract!("tests/presets/alloy-v1.json", "tests/foundry.json");
+
+#[test]
+fn test_preset_alloy_v1() {
+    let wallet = PersonalWallet {
+        client: ethbind_rt::Client,
+        address: alloy::primitives::Address,
+    };
+
+    assert_eq!(format!("{:?}", wallet.address), "Address");
+
+    let token = Token {
+        client: ethbind_rt::Client,
+        address: alloy::primitives::Address,
+    };
+
+    // The stand-in runtime doesn't encode parameters, the error is returned by the binding.
+    let err =
+        block_on(token.balance_of(alloy::primitives::Address)).expect_err("Encode parameters");
+
+    assert!(err.to_string().contains("stand-in runtime"));
+}
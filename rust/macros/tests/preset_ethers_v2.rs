@@ -0,0 +1,51 @@
+use ethbind_rust::mock::block_on;
+use ethbind_rust_macros::contract;
+
+mod ethbind_rt;
+
+/// Stand-in of `ethers` 2.x with the same type paths.
+#[allow(unused)]
+mod ethers {
+    pub mod types {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        pub struct Address;
+
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        pub struct Bytes;
+
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        pub struct U256;
+
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        pub struct I256;
+
+        #[derive(Debug, Default, Serialize, Deserialize)]
+        pub struct TransactionReceipt;
+    }
+}
+
+contract!("tests/presets/ethers-v2.json", "tests/abi.json");
+
+contract!("tests/presets/ethers-v2.json", "tests/foundry.json");
+
+#[test]
+fn test_preset_ethers_v2() {
+    let wallet = PersonalWallet {
+        client: ethbind_rt::Client,
+        address: ethers::types::Address,
+    };
+
+    assert_eq!(format!("{:?}", wallet.address), "Address");
+
+    let token = Token {
+        client: ethbind_rt::Client,
+        address: ethers::types::Address,
+    };
+
+    // The stand-in runtime doesn't encode parameters, the error is returned by the binding.
+    let err = block_on(token.balance_of(ethers::types::Address)).expect_err("Encode parameters");
+
+    assert!(err.to_string().contains("stand-in runtime"));
+}
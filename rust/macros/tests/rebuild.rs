@@ -0,0 +1,107 @@
+//! Build a scratch crate using `contract!` twice, the bindings must be regenerated after the abi file is changed.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+const MAIN: &str = r#"
+#[allow(unused)]
+mod mock {
+    pub use serde::{Deserialize, Serialize};
+
+    pub struct Client;
+
+    #[derive(Debug, Default, Serialize, Deserialize)]
+    pub struct Address;
+}
+
+ethbind_rust_macros::contract!(Registry, "mapping.json", "abi.json");
+
+fn main() {
+    println!("{}", RegistryRegistered::abi_json());
+}
+"#;
+
+fn abi(field: &str) -> String {
+    format!(
+        r#"[{{
+            "type": "event",
+            "name": "Registered",
+            "inputs": [{{ "name": "{}", "type": "address", "indexed": true }}],
+            "anonymous": false
+        }}]"#,
+        field
+    )
+}
+
+/// `cargo run` the scratch crate, returns the stdout.
+fn cargo_run(project_dir: &Path) -> String {
+    // All dependencies are locked and fetched by the workspace build.
+    let output = Command::new(env!("CARGO"))
+        .args(["run", "--quiet", "--offline"])
+        .current_dir(project_dir)
+        .output()
+        .expect("Run cargo");
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    String::from_utf8(output.stdout).expect("Read stdout")
+}
+
+#[test]
+fn test_rebuild() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    let project_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("rebuild");
+
+    fs::create_dir_all(project_dir.join("src")).expect("Create project dir");
+
+    fs::write(
+        project_dir.join("Cargo.toml"),
+        format!(
+            r#"
+[package]
+name = "ethbind-rebuild-test"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[dependencies]
+ethbind-rust-macros = {{ path = {:?} }}
+serde = {{ version = "1", features = ["derive"] }}
+
+[workspace]
+"#,
+            manifest_dir
+        ),
+    )
+    .expect("Write Cargo.toml");
+
+    fs::copy(
+        manifest_dir.join("../../Cargo.lock"),
+        project_dir.join("Cargo.lock"),
+    )
+    .expect("Copy Cargo.lock");
+
+    fs::copy(
+        manifest_dir.join("tests/mapping.json"),
+        project_dir.join("mapping.json"),
+    )
+    .expect("Copy mapping");
+
+    fs::write(project_dir.join("src/main.rs"), MAIN).expect("Write main.rs");
+
+    fs::write(project_dir.join("abi.json"), abi("owner")).expect("Write abi");
+
+    assert!(cargo_run(&project_dir).contains(r#""name":"owner""#));
+
+    fs::write(project_dir.join("abi.json"), abi("account")).expect("Write abi");
+
+    assert!(cargo_run(&project_dir).contains(r#""name":"account""#));
+}
@@ -0,0 +1,30 @@
+use std::{fs, path::PathBuf};
+
+/// Copy json fixtures into the `trybuild` project directory, which is the `CARGO_MANIFEST_DIR`
+/// seen by the macro under test, so the fixture paths resolve the same as in the other tests.
+fn copy_fixtures() {
+    let project_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .join("../tests/trybuild")
+        .join(env!("CARGO_PKG_NAME"));
+
+    fs::create_dir_all(project_dir.join("tests/ui")).expect("Create trybuild project dir");
+
+    for dir in ["tests", "tests/ui"] {
+        for entry in fs::read_dir(dir).expect("Read fixtures dir") {
+            let path = entry.expect("Read fixture").path();
+
+            if path.extension().is_some_and(|c| c == "json") {
+                fs::copy(&path, project_dir.join(&path)).expect("Copy fixture");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ui() {
+    copy_fixtures();
+
+    let t = trybuild::TestCases::new();
+
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,6 @@
+use ethbind_rust_macros::bind;
+
+#[bind(mapping = "tests/mapping.json", abi = "tests/ui/registry.json", module = bindings)]
+mod registry {}
+
+fn main() {}
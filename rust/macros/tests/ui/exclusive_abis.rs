@@ -0,0 +1,9 @@
+use ethbind_rust_macros::contract;
+
+contract!(
+    name = Registry,
+    mapping = "tests/mapping.json",
+    abis = ["tests/ui/registry.json"],
+);
+
+fn main() {}
@@ -0,0 +1,5 @@
+use ethbind_rust_macros::contract;
+
+contract!(mapping = "tests/mapping.json", abis = ["tests/missing/*.json"]);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use ethbind_rust_macros::contract;
+
+contract!(
+    mapping = "tests/mapping.json",
+    abi = ["function balanceOf(address owner) view returns (uint256)"],
+);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use ethbind_rust_macros::contract;
+
+contract!(
+    mapping = "tests/mapping.json",
+    abis = ["tests/ui/registry.json", "tests/ui/invalid_abi.json"],
+);
+
+fn main() {}
@@ -0,0 +1,5 @@
+use ethbind_rust_macros::contract;
+
+contract!(mapping = "tests/mapping.json", abi = "tests/abi.json", format = truffle);
+
+fn main() {}
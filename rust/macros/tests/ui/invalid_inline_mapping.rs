@@ -0,0 +1,9 @@
+use ethbind_rust_macros::contract;
+
+contract!(
+    Erc20,
+    ["function balanceOf(address owner) view returns (uint256)"],
+    ["function balanceOf(address owner) view returns (uint256)"]
+);
+
+fn main() {}
@@ -0,0 +1,5 @@
+use ethbind_rust_macros::contract;
+
+contract!(Registry, "tests/ui/invalid_runtime_type.json", "tests/ui/registry.json");
+
+fn main() {}
@@ -0,0 +1,12 @@
+use ethbind_rust_macros::contract;
+
+contract!(
+    name = Erc20,
+    mapping = "tests/mapping.json",
+    abi = [
+        "function balanceOf(address owner) view returns (uint256)",
+        "function transfer(address to, uint256 value) returns bool",
+    ],
+);
+
+fn main() {}
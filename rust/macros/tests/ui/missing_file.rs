@@ -0,0 +1,5 @@
+use ethbind_rust_macros::contract;
+
+contract!(Registry, "tests/mapping.json", "tests/ui/missing.json");
+
+fn main() {}
@@ -0,0 +1,5 @@
+use ethbind_rust_macros::contract;
+
+contract!(Registry, "tests/ui/partial_mapping.json", "tests/ui/registry.json");
+
+fn main() {}
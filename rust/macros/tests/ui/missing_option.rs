@@ -0,0 +1,5 @@
+use ethbind_rust_macros::contract;
+
+contract!(name = Lock, mapping = "tests/mapping.json");
+
+fn main() {}
@@ -0,0 +1,250 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::RustGenerator;
+
+use super::parse_runtime_type;
+
+impl RustGenerator {
+    /// Generate `ContractClient` trait, which declares all runtime methods called by generated contracts.
+    pub(crate) fn to_client_trait<R: ethbind_gen::RuntimeBinder>(
+        &self,
+        runtime_binder: &mut R,
+    ) -> anyhow::Result<TokenStream> {
+        let address = self.to_runtime_type_token_stream(runtime_binder, "address")?;
+
+        let opts_type = self.to_runtime_type_token_stream(runtime_binder, "rt_opts")?;
+
+        let error_type = self.to_runtime_type_token_stream(runtime_binder, "rt_error")?;
+
+        let receipt_type = self.to_runtime_type_token_stream(runtime_binder, "rt_receipt")?;
+
+        let serialize = self.to_runtime_type_token_stream(runtime_binder, "rt_serialize_derive")?;
+
+        let deserialize =
+            self.to_runtime_type_token_stream(runtime_binder, "rt_deserialize_derive")?;
+
+        let mut methods = vec![];
+
+        for flavor in self.call_mode.flavors() {
+            let deploy_contract = flavor.ident("deploy_contract");
+            let eth_call = flavor.ident("eth_call");
+            let send_raw_transaction = flavor.ident("send_raw_transaction");
+
+            let deploy_returns =
+                flavor.trait_returns(quote!(std::result::Result<#address, #error_type>));
+            let call_returns =
+                flavor.trait_returns(quote!(std::result::Result<Self::Data, #error_type>));
+            let send_returns =
+                flavor.trait_returns(quote!(std::result::Result<#receipt_type, #error_type>));
+
+            methods.push(quote! {
+                /// Deploy contract `bytecode` with encoded constructor parameters, returns the contract address.
+                fn #deploy_contract(&self, signature: &str, inputs: Self::Data, bytecode: &str, ops: #opts_type) -> #deploy_returns;
+
+                /// Call contract read-only function, returns the encoded outputs.
+                fn #eth_call(&self, signature: &str, address: &#address, inputs: Self::Data) -> #call_returns;
+
+                /// Send contract function transaction.
+                fn #send_raw_transaction(&self, signature: &str, address: &#address, inputs: Self::Data, ops: #opts_type) -> #send_returns;
+            });
+
+            if self.call_builder {
+                let gas_type = self.to_gas_type(runtime_binder)?;
+
+                let estimate_gas = flavor.ident("estimate_gas");
+
+                let estimate_gas_returns =
+                    flavor.trait_returns(quote!(std::result::Result<#gas_type, #error_type>));
+
+                methods.push(quote! {
+                    /// Estimate gas used by contract function transaction.
+                    fn #estimate_gas(&self, signature: &str, address: &#address, inputs: &Self::Data, ops: &#opts_type) -> #estimate_gas_returns;
+                });
+            }
+        }
+
+        let (call_builder, encode_calldata) = if self.call_builder {
+            (
+                self.to_call_builder(runtime_binder)?,
+                quote! {
+                    /// Returns calldata of the function `selector` followed by the abi encoded parameters `inputs`.
+                    fn encode_calldata(selector: [u8; 4], inputs: &Self::Data) -> std::result::Result<Self::Data, #error_type>;
+                },
+            )
+        } else {
+            (quote!(), quote!())
+        };
+
+        Ok(quote! {
+            /// Runtime client required by generated contract bindings.
+            ///
+            /// Async methods return `Send` futures, which can be implemented by `async fn` if the client is `Sync`
+            /// and the futures hold no thread-local state.
+            pub trait ContractClient {
+                /// Abi encoded data type
+                type Data;
+
+                /// Abi encode contract call parameters, `value` is the parameter or the tuple of parameters.
+                fn abi_encode<T: #serialize>(value: &T) -> std::result::Result<Self::Data, #error_type>;
+
+                /// Abi decode contract call outputs.
+                fn abi_decode<T: for<'de> #deserialize<'de>>(data: Self::Data) -> std::result::Result<T, #error_type>;
+
+                #encode_calldata
+
+                #(#methods)*
+            }
+
+            #call_builder
+        })
+    }
+
+    /// Returns the runtime type of estimated gas, which is the `uint256` runtime type.
+    fn to_gas_type<R: ethbind_gen::RuntimeBinder>(
+        &self,
+        runtime_binder: &mut R,
+    ) -> anyhow::Result<TokenStream> {
+        let gas_type = runtime_binder
+            .to_runtime_type(&"uint256".parse()?)?
+            .ok_or_else(|| anyhow::format_err!("uint256 runtime type is required by gas"))?;
+
+        parse_runtime_type(gas_type)
+    }
+
+    /// Generate `ContractCall` builder returned by contract functions, see [`call_builder`](RustGenerator::call_builder).
+    fn to_call_builder<R: ethbind_gen::RuntimeBinder>(
+        &self,
+        runtime_binder: &mut R,
+    ) -> anyhow::Result<TokenStream> {
+        let address = self.to_runtime_type_token_stream(runtime_binder, "address")?;
+
+        let opts_type = self.to_runtime_type_token_stream(runtime_binder, "rt_opts")?;
+
+        let error_type = self.to_runtime_type_token_stream(runtime_binder, "rt_error")?;
+
+        let receipt_type = self.to_runtime_type_token_stream(runtime_binder, "rt_receipt")?;
+
+        let deserialize =
+            self.to_runtime_type_token_stream(runtime_binder, "rt_deserialize_derive")?;
+
+        let gas_type = self.to_gas_type(runtime_binder)?;
+
+        let mut methods = vec![];
+
+        for flavor in self.call_mode.flavors() {
+            let asyncness = &flavor.asyncness;
+            let awaiting = &flavor.awaiting;
+
+            let call = flavor.ident("call");
+            let send = flavor.ident("send");
+            let estimate_gas = flavor.ident("estimate_gas");
+
+            let eth_call = flavor.ident("eth_call");
+            let send_raw_transaction = flavor.ident("send_raw_transaction");
+
+            methods.push(quote! {
+                /// Execute as read-only call, returns the decoded outputs.
+                pub #asyncness fn #call(self) -> std::result::Result<R, #error_type>
+                where R: for<'de> #deserialize<'de>,
+                {
+                    let outputs = self.client.#eth_call(self.signature, self.target, self.inputs)#awaiting?;
+
+                    C::abi_decode(outputs)
+                }
+
+                /// Send as transaction with the call options.
+                pub #asyncness fn #send(self) -> std::result::Result<#receipt_type, #error_type> {
+                    self.client.#send_raw_transaction(self.signature, self.target, self.inputs, self.ops)#awaiting
+                }
+
+                /// Estimate gas used by sending this call as transaction.
+                pub #asyncness fn #estimate_gas(&self) -> std::result::Result<#gas_type, #error_type> {
+                    self.client.#estimate_gas(self.signature, self.target, &self.inputs, &self.ops)#awaiting
+                }
+            });
+        }
+
+        Ok(quote! {
+            /// Contract function call built by bindings, `R` is the function outputs type.
+            ///
+            /// Nothing is sent until [`call`](ContractCall::call) or [`send`](ContractCall::send) is invoked,
+            /// so the [`calldata`](ContractCall::calldata) can also be exported, e.g: to build multisig proposals or batched transactions.
+            pub struct ContractCall<'a, C: ContractClient, R> {
+                client: &'a C,
+                target: &'a #address,
+                signature: &'static str,
+                selector: [u8; 4],
+                inputs: C::Data,
+                ops: #opts_type,
+                _outputs: std::marker::PhantomData<fn() -> R>,
+            }
+
+            impl<'a, C: ContractClient, R> ContractCall<'a, C, R> {
+                /// Create call of function `signature` with abi encoded parameters `inputs` and default options.
+                pub fn new(client: &'a C, target: &'a #address, signature: &'static str, selector: [u8; 4], inputs: C::Data) -> Self {
+                    Self {
+                        client,
+                        target,
+                        signature,
+                        selector,
+                        inputs,
+                        ops: Default::default(),
+                        _outputs: std::marker::PhantomData,
+                    }
+                }
+
+                /// Returns the contract address.
+                pub fn target(&self) -> &#address {
+                    self.target
+                }
+
+                /// Returns the function signature, e.g: `transfer(address,uint256)`.
+                pub fn signature(&self) -> &'static str {
+                    self.signature
+                }
+
+                /// Returns the function selector.
+                pub fn selector(&self) -> [u8; 4] {
+                    self.selector
+                }
+
+                /// Returns the calldata, which is the [`selector`](ContractCall::selector) followed by the encoded parameters.
+                pub fn calldata(&self) -> std::result::Result<C::Data, #error_type> {
+                    C::encode_calldata(self.selector, &self.inputs)
+                }
+
+                /// Returns the abi encoded parameters without the [`selector`](ContractCall::selector).
+                pub fn inputs(&self) -> &C::Data {
+                    &self.inputs
+                }
+
+                /// Consume the call and returns the abi encoded parameters without the selector.
+                pub fn into_inputs(self) -> C::Data {
+                    self.inputs
+                }
+
+                /// Returns the call options, e.g: the transferred value, gas limit or nonce.
+                pub fn options(&self) -> &#opts_type {
+                    &self.ops
+                }
+
+                /// Returns mutable call options.
+                ///
+                /// The options type is the runtime `rt_opts` type, whose fields are unknown to the bindings,
+                /// so the transferred value, gas limit or nonce is only set through it, there are no dedicated setters.
+                pub fn options_mut(&mut self) -> &mut #opts_type {
+                    &mut self.ops
+                }
+
+                /// Replace the call options.
+                pub fn ops(mut self, ops: #opts_type) -> Self {
+                    self.ops = ops;
+                    self
+                }
+
+                #(#methods)*
+            }
+        })
+    }
+}
@@ -1,15 +1,25 @@
+use std::collections::HashMap;
+
 use ethbind_gen::{Contract, File};
-use heck::ToUpperCamelCase;
+use ethbind_json::NatSpec;
+use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
+use super::to_doc_attrs;
+
 #[derive(Debug, Default)]
-#[allow(unused)]
 pub(crate) struct ContractGenerator {
     pub(crate) contract_name: String,
+    pub(crate) natspec: NatSpec,
+    /// Extra derive macros of generated events and errors
+    pub(crate) derives: Vec<TokenStream>,
     fn_token_streams: Vec<TokenStream>,
     event_token_streams: Vec<TokenStream>,
-    error_token_streams: Vec<TokenStream>,
+    /// Error enum variants and the `signature` fn match arms
+    error_token_streams: Vec<(TokenStream, TokenStream)>,
+    /// Declared error variant names and their counters, see [`error_variant_name`](ContractGenerator::error_variant_name)
+    error_names: HashMap<String, usize>,
 }
 
 impl ContractGenerator {
@@ -28,33 +38,119 @@ impl ContractGenerator {
         self.event_token_streams.push(token_stream);
     }
 
+    pub(crate) fn add_error_token_stream(&mut self, variant: TokenStream, signature: TokenStream) {
+        self.error_token_streams.push((variant, signature));
+    }
+
+    /// Returns unique error variant name, errors with the same name are suffixed by index, e.g: `Bad`,`Bad0`,`Bad1`
+    pub(crate) fn error_variant_name(&mut self, name: &str) -> String {
+        let counter = self.error_names.entry(name.to_owned()).or_insert(0);
+
+        *counter += 1;
+
+        if *counter == 1 {
+            name.to_owned()
+        } else {
+            format!("{}{}", name, *counter - 2)
+        }
+    }
+
+    /// Returns the ident of generated contract errors enum.
+    pub(crate) fn error_ident(&self) -> proc_macro2::Ident {
+        format_ident!("{}Error", self.contract_name.to_upper_camel_case())
+    }
+
+    /// Returns generated rust module name, e.g: `PersonalWallet` => `personal_wallet`
+    pub(crate) fn module_name(&self) -> String {
+        self.contract_name.to_snake_case()
+    }
+
+    /// Generate contract file, `structs` are the struct declarations only used by this contract,
+    /// `prelude` is inserted at the beginning of file.
+    ///
+    /// If `rt_client` is `None`, the contract is generic over the generated `ContractClient` trait.
     pub(crate) fn finalize(
         &self,
-        rt_client: &TokenStream,
+        rt_client: Option<&TokenStream>,
         rt_address: &TokenStream,
+        rt_derives: &TokenStream,
+        prelude: TokenStream,
+        structs: Vec<&TokenStream>,
     ) -> anyhow::Result<Contract> {
         let fn_token_streams = &self.fn_token_streams;
         let event_token_streams = &self.event_token_streams;
-        // let error_token_streams = &self.error_token_streams;
 
         let ident = format_ident!("{}", &self.contract_name.to_upper_camel_case());
 
-        let token_stream = quote! {
-            pub struct #ident{
-                pub client: #rt_client,
-                pub address: #rt_address,
-            }
+        let doc_attrs = to_doc_attrs(&self.natspec.contract(), &[], &[]);
 
-            impl #ident {
-                #(#fn_token_streams)*
+        let derives = &self.derives;
+
+        let error_token_stream = if self.error_token_streams.is_empty() {
+            quote!()
+        } else {
+            let error_ident = self.error_ident();
+
+            let (variants, signatures): (Vec<_>, Vec<_>) =
+                self.error_token_streams.iter().cloned().unzip();
+
+            quote! {
+                #[derive(#rt_derives #(,#derives)*)]
+                pub enum #error_ident {
+                    #(#variants,)*
+                }
+
+                impl #error_ident {
+                    /// Returns the canonical signature of this contract error
+                    pub fn signature(&self) -> &'static str {
+                        match self {
+                            #(#signatures,)*
+                        }
+                    }
+                }
             }
+        };
+
+        let contract_token_stream = match rt_client {
+            Some(rt_client) => quote! {
+                #doc_attrs
+                pub struct #ident{
+                    pub client: #rt_client,
+                    pub address: #rt_address,
+                }
+
+                impl #ident {
+                    #(#fn_token_streams)*
+                }
+            },
+            None => quote! {
+                #doc_attrs
+                pub struct #ident<C>{
+                    pub client: C,
+                    pub address: #rt_address,
+                }
+
+                impl<C: ContractClient> #ident<C> {
+                    #(#fn_token_streams)*
+                }
+            },
+        };
+
+        let token_stream = quote! {
+            #prelude
+
+            #(#structs)*
+
+            #contract_token_stream
 
             #(#event_token_streams)*
+
+            #error_token_stream
         };
 
         Ok(Contract {
             files: vec![File {
-                name: self.contract_name.clone(),
+                name: format!("{}.rs", self.module_name()),
                 data: token_stream.to_string(),
             }],
         })
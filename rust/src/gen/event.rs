@@ -1,28 +1,35 @@
-use ethbind_json::Parameter;
+use ethbind_json::{Doc, Parameter};
 use heck::ToSnakeCase;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
 use crate::RustGenerator;
 
+use super::to_doc_lines;
+
+#[allow(clippy::wrong_self_convention)]
 impl RustGenerator {
+    /// Convert `params` to struct field list, the `@param` docs are attached to fields.
     pub(crate) fn to_event_field_list<R: ethbind_gen::RuntimeBinder>(
-        &self,
+        &mut self,
         runtime_binder: &mut R,
         params: &[Parameter],
+        doc: &Doc,
     ) -> anyhow::Result<Vec<TokenStream>> {
         let mut token_streams = vec![];
 
         for (index, param) in params.iter().enumerate() {
             let type_ident = self.to_rust_type(runtime_binder, param)?;
 
-            let var_ident = if param.name != "" {
+            let var_ident = if !param.name.is_empty() {
                 format_ident!("{}", param.name.to_snake_case())
             } else {
                 format_ident!("p{}", index)
             };
 
-            token_streams.push(quote!(#var_ident: #type_ident));
+            let doc_attrs = to_doc_lines(doc.params.get(&param.name).map_or("", |c| c.as_str()));
+
+            token_streams.push(quote!(#doc_attrs #var_ident: #type_ident));
         }
 
         Ok(token_streams)
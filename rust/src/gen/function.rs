@@ -5,6 +5,7 @@ use quote::{format_ident, quote};
 
 use crate::RustGenerator;
 
+#[allow(clippy::wrong_self_convention)]
 impl RustGenerator {
     /// Convert `params` to generic list
     pub(crate) fn to_param_list<R: ethbind_gen::RuntimeBinder>(
@@ -17,7 +18,7 @@ impl RustGenerator {
         for (index, param) in params.iter().enumerate() {
             let type_ident = format_ident!("P{}", index);
 
-            let var_ident = if param.name != "" {
+            let var_ident = if !param.name.is_empty() {
                 format_ident!("{}", param.name.to_snake_case())
             } else {
                 format_ident!("p{}", index)
@@ -48,7 +49,7 @@ impl RustGenerator {
 
     /// Convert fn param list to fn where clause list
     pub(crate) fn to_where_clause_list<R: ethbind_gen::RuntimeBinder>(
-        &self,
+        &mut self,
         runtime_binder: &mut R,
         params: &[Parameter],
     ) -> anyhow::Result<Vec<TokenStream>> {
@@ -74,7 +75,7 @@ impl RustGenerator {
         let mut token_streams = vec![];
 
         for (index, param) in params.iter().enumerate() {
-            let var_ident = if param.name != "" {
+            let var_ident = if !param.name.is_empty() {
                 format_ident!("{}", param.name.to_snake_case())
             } else {
                 format_ident!("p{}", index)
@@ -95,7 +96,7 @@ impl RustGenerator {
         let mut token_streams = vec![];
 
         for (index, param) in params.iter().enumerate() {
-            let param_ident = if param.name != "" {
+            let param_ident = if !param.name.is_empty() {
                 format_ident!("{}", param.name.to_snake_case())
             } else {
                 format_ident!("p{}", index)
@@ -119,44 +120,12 @@ impl RustGenerator {
         }
     }
 
-    #[allow(unused)]
-    pub(crate) fn to_rust_type<R: ethbind_gen::RuntimeBinder>(
-        &self,
-        runtime_binder: &mut R,
-        param: &Parameter,
-    ) -> anyhow::Result<TokenStream> {
-        if let Some(runtime_type) = runtime_binder.to_runtime_type(&param.r#type)? {
-            let runtime_type: TokenStream = runtime_type
-                .parse()
-                .map_err(|err| anyhow::format_err!("{}", err))?;
-
-            return Ok(runtime_type);
-        } else {
-            let components = param
-                .components
-                .as_ref()
-                .expect("Tuple parameter's components field is None");
-
-            let mut tuple_token_streams = vec![];
-
-            for c in components {
-                tuple_token_streams.push(self.to_rust_type(runtime_binder, c)?);
-            }
-
-            return Ok(quote!((#(#tuple_token_streams,)*)));
-        }
-    }
-
     pub(crate) fn to_outputs_type<R: ethbind_gen::RuntimeBinder>(
-        &self,
+        &mut self,
         runtime_binder: &mut R,
         outputs: &[Parameter],
     ) -> anyhow::Result<TokenStream> {
-        let mut tuple_token_streams = vec![];
-
-        for param in outputs {
-            tuple_token_streams.push(self.to_rust_type(runtime_binder, param)?);
-        }
+        let tuple_token_streams = self.to_rust_types(runtime_binder, outputs)?;
 
         if outputs.len() <= 1 {
             Ok(quote!(#(#tuple_token_streams)*))
@@ -173,11 +142,11 @@ impl RustGenerator {
         r#type: &Type,
         components: &[Parameter],
     ) -> anyhow::Result<TokenStream> {
-        if let Some(_) = runtime_binder.to_runtime_type(r#type)? {
+        if runtime_binder.to_runtime_type(r#type)?.is_some() {
             let var_ident: TokenStream =
                 var_name.parse().map_err(|e| anyhow::format_err!("{}", e))?;
 
-            return Ok(quote!(outputs.abi_encode(&#var_ident)?;));
+            Ok(quote!(outputs.abi_encode(&#var_ident)?;))
         } else {
             let mut tuple_token_streams = vec![];
 
@@ -192,11 +161,11 @@ impl RustGenerator {
                 )?);
             }
 
-            return Ok(quote! {
+            Ok(quote! {
                 outputs.abi_start_encode_tuple()?;
                 #(#tuple_token_streams)*
                 outputs.abi_end_encode_tuple()?;
-            });
+            })
         }
     }
 }
@@ -1,9 +1,11 @@
-use ethbind_gen::Generator;
+use ethbind_gen::{Contract, ContractOptions, File, Generator};
 use ethbind_json::*;
 use heck::{ToSnakeCase, ToUpperCamelCase};
 use quote::{format_ident, quote};
 
-use crate::RustGenerator;
+use crate::{ModuleLayout, RustGenerator};
+
+use super::{to_doc_attrs, to_doc_lines, Flavor};
 
 impl Generator for RustGenerator {
     fn begin<R: ethbind_gen::RuntimeBinder>(
@@ -24,17 +26,147 @@ impl Generator for RustGenerator {
         Ok(())
     }
 
+    fn generate_natspec<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        natspec: &NatSpec,
+    ) -> anyhow::Result<()> {
+        self.current_contract().natspec = natspec.clone();
+
+        Ok(())
+    }
+
+    fn generate_options<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        options: &ContractOptions,
+    ) -> anyhow::Result<()> {
+        let mut derives = vec![];
+
+        for derive in &options.derives {
+            derives.push(
+                derive
+                    .parse()
+                    .map_err(|err| anyhow::format_err!("Parse derive {}: {}", derive, err))?,
+            );
+        }
+
+        self.current_contract().derives = derives;
+
+        Ok(())
+    }
+
     fn finalize<R: ethbind_gen::RuntimeBinder>(
         self,
         runtime_binder: &mut R,
-    ) -> anyhow::Result<Vec<ethbind_gen::Contract>> {
-        let client_type = self.to_runtime_type_token_stream(runtime_binder, "rt_client")?;
+    ) -> anyhow::Result<Vec<Contract>> {
+        let client_type = if self.client_trait {
+            None
+        } else {
+            Some(self.to_runtime_type_token_stream(runtime_binder, "rt_client")?)
+        };
+
         let adress = self.to_runtime_type_token_stream(runtime_binder, "address")?;
 
+        let serialize_derive_macro =
+            self.to_runtime_type_token_stream(runtime_binder, "rt_serialize_derive")?;
+
+        let deserialize_derive_macro =
+            self.to_runtime_type_token_stream(runtime_binder, "rt_deserialize_derive")?;
+
+        let derives = quote!(#serialize_derive_macro,#deserialize_derive_macro);
+
         let mut contracts = vec![];
 
-        for c in &self.contracts {
-            contracts.push(c.finalize(&client_type, &adress)?);
+        for (index, c) in self.contracts.iter().enumerate() {
+            let structs = self
+                .structs
+                .iter()
+                .filter(|s| !s.is_shared() && s.users.contains(&index))
+                .map(|s| &s.token_stream)
+                .collect();
+
+            let use_types = self
+                .structs
+                .iter()
+                .any(|s| s.is_shared() && s.users.contains(&index));
+
+            let mut prelude = quote!();
+
+            if self.layout == ModuleLayout::Modules {
+                if use_types {
+                    prelude.extend(quote!(
+                        use super::types::*;
+                    ));
+                }
+
+                if self.client_trait {
+                    prelude.extend(quote!(
+                        use super::client::*;
+                    ));
+                }
+            }
+
+            contracts.push(c.finalize(
+                client_type.as_ref(),
+                &adress,
+                &derives,
+                prelude,
+                structs,
+            )?);
+        }
+
+        let shared_structs = self
+            .structs
+            .iter()
+            .filter(|s| s.is_shared())
+            .map(|s| &s.token_stream)
+            .collect::<Vec<_>>();
+
+        let mut files = vec![];
+
+        if !shared_structs.is_empty() {
+            files.push(File {
+                name: "types.rs".to_owned(),
+                data: quote!(#(#shared_structs)*).to_string(),
+            });
+        }
+
+        if self.client_trait {
+            files.push(File {
+                name: "client.rs".to_owned(),
+                data: self.to_client_trait(runtime_binder)?.to_string(),
+            });
+        }
+
+        if self.layout == ModuleLayout::Modules {
+            let mut modules = self
+                .contracts
+                .iter()
+                .map(|c| c.module_name())
+                .collect::<Vec<_>>();
+
+            if !shared_structs.is_empty() {
+                modules.insert(0, "types".to_owned());
+            }
+
+            if self.client_trait {
+                modules.insert(0, "client".to_owned());
+            }
+
+            // Already formatted, rustfmt can't format `mod` declarations without the module files.
+            files.push(File {
+                name: "mod.rs".to_owned(),
+                data: modules
+                    .iter()
+                    .map(|c| format!("pub mod {};\npub use {}::*;\n", c, c))
+                    .collect(),
+            });
+        }
+
+        // The shared files across all bound contracts.
+        if !files.is_empty() {
+            contracts.push(Contract { files });
         }
 
         Ok(contracts)
@@ -46,14 +178,28 @@ impl Generator for RustGenerator {
         contructor: &Constructor,
         deploy_bytes: &str,
     ) -> anyhow::Result<()> {
-        let client_type = self.to_runtime_type_token_stream(runtime_binder, "rt_client")?;
-
         let opts_type = self.to_runtime_type_token_stream(runtime_binder, "rt_opts")?;
 
-        let abi_encode = self.to_runtime_type_token_stream(runtime_binder, "rt_abi_serialize")?;
-
         let error_type = self.to_runtime_type_token_stream(runtime_binder, "rt_error")?;
 
+        // With client trait, the contract is generic over `C: ContractClient`,
+        // otherwise `C` is converted into `rt_client`.
+        let (client_generic, client_where_clause, client_try_into, abi_encode) = if self
+            .client_trait
+        {
+            (quote!(), quote!(), quote!(), quote!(C::abi_encode))
+        } else {
+            let client_type = self.to_runtime_type_token_stream(runtime_binder, "rt_client")?;
+
+            (
+                quote!(C,),
+                quote!(C: TryInto<#client_type>, C::Error: std::error::Error + Sync + Send + 'static,),
+                // `rt_client` deploy method may take `&mut self`
+                quote!(#[allow(unused_mut)] let mut client = client.try_into()?;),
+                self.to_runtime_type_token_stream(runtime_binder, "rt_abi_serialize")?,
+            )
+        };
+
         let generic_list = self.to_generic_list(runtime_binder, &contructor.inputs)?;
 
         let param_list = self.to_param_list(runtime_binder, &contructor.inputs)?;
@@ -66,47 +212,92 @@ impl Generator for RustGenerator {
 
         let fn_signature = contructor.signature();
 
-        self.current_contract().add_fn_token_stream(quote! {
-            pub async fn deploy_with<C, #(#generic_list,)* Ops>(client: C, #(#param_list,)* ops: Ops) -> std::result::Result<Self,#error_type>
-            where C: TryInto<#client_type>, C::Error: std::error::Error + Sync + Send + 'static,
-            Ops: TryInto<#opts_type>, Ops::Error: std::error::Error + Sync + Send + 'static,
-            #(#where_clause_list,)*
-            {
-                let mut client = client.try_into()?;
-                #(#try_into_list;)*
-                let ops = ops.try_into()?;
+        let doc_attrs = to_doc_attrs(
+            &self.current_contract().natspec.constructor(),
+            &contructor.inputs,
+            &[],
+        );
 
-                let outputs = #abi_encode(&#abi_encode_list)?;
+        for flavor in self.call_mode.flavors() {
+            let Flavor {
+                asyncness,
+                awaiting,
+                ..
+            } = &flavor;
 
-                let address = client.deploy_contract(#fn_signature, outputs,#deploy_bytes,ops).await?;
+            let deploy_ident = flavor.ident("deploy");
+            let deploy_with_ident = flavor.ident("deploy_with");
+            let deploy_contract = flavor.ident("deploy_contract");
 
-                Ok(Self{ client, address })
-            }
+            self.current_contract().add_fn_token_stream(quote! {
+                #doc_attrs
+                pub #asyncness fn #deploy_with_ident<#client_generic #(#generic_list,)* Ops>(client: C, #(#param_list,)* ops: Ops) -> std::result::Result<Self,#error_type>
+                where #client_where_clause
+                Ops: TryInto<#opts_type>, Ops::Error: std::error::Error + Sync + Send + 'static,
+                #(#where_clause_list,)*
+                {
+                    #client_try_into
+                    #(#try_into_list;)*
+                    let ops = ops.try_into()?;
 
-            pub async fn deploy<C, #(#generic_list,)* Ops>(client: C, #(#param_list,)*) -> std::result::Result<Self,#error_type>
-            where C: TryInto<#client_type>, C::Error: std::error::Error + Sync + Send + 'static,
-            #(#where_clause_list,)*
-            {
-                let mut client = client.try_into()?;
-                #(#try_into_list;)*
-            
-                let outputs = #abi_encode(&#abi_encode_list)?;
+                    let outputs = #abi_encode(&#abi_encode_list)?;
 
-                let address = client.deploy_contract(#fn_signature, outputs,#deploy_bytes, Default::default()).await?;
+                    let address = client.#deploy_contract(#fn_signature, outputs,#deploy_bytes,ops)#awaiting?;
 
-                Ok(Self{ client, address })
-            }
-        });
+                    Ok(Self{ client, address })
+                }
+
+                #doc_attrs
+                pub #asyncness fn #deploy_ident<#client_generic #(#generic_list,)* Ops>(client: C, #(#param_list,)*) -> std::result::Result<Self,#error_type>
+                where #client_where_clause
+                #(#where_clause_list,)*
+                {
+                    #client_try_into
+                    #(#try_into_list;)*
+
+                    let outputs = #abi_encode(&#abi_encode_list)?;
+
+                    let address = client.#deploy_contract(#fn_signature, outputs,#deploy_bytes, Default::default())#awaiting?;
+
+                    Ok(Self{ client, address })
+                }
+            });
+        }
 
         Ok(())
     }
 
     fn generate_error<R: ethbind_gen::RuntimeBinder>(
         &mut self,
-        _runtime_binder: &mut R,
-        _error: &Error,
+        runtime_binder: &mut R,
+        error: &Error,
     ) -> anyhow::Result<()> {
-        // Skip generate error binding code
+        log::trace!("generate error {}", error.name);
+
+        let doc = self.current_contract().natspec.error(error);
+
+        let doc_attrs = to_doc_attrs(&doc, &[], &[]);
+
+        let field_list = self.to_event_field_list(runtime_binder, &error.inputs, &doc)?;
+
+        let variant_ident = format_ident!(
+            "{}",
+            self.current_contract()
+                .error_variant_name(&error.name.to_upper_camel_case())
+        );
+
+        let signature = error.signature();
+
+        self.current_contract().add_error_token_stream(
+            quote! {
+                #doc_attrs
+                #variant_ident {
+                    #(#field_list,)*
+                }
+            },
+            quote!(Self::#variant_ident { .. } => #signature),
+        );
+
         Ok(())
     }
 
@@ -117,7 +308,11 @@ impl Generator for RustGenerator {
     ) -> anyhow::Result<()> {
         log::trace!("generate event {}", event.name);
 
-        let event_field_list = self.to_event_field_list(runtime_binder, &event.inputs)?;
+        let doc = self.current_contract().natspec.event(event);
+
+        let doc_attrs = to_doc_attrs(&doc, &[], &[]);
+
+        let event_field_list = self.to_event_field_list(runtime_binder, &event.inputs, &doc)?;
 
         let serialize_derive_macro =
             self.to_runtime_type_token_stream(runtime_binder, "rt_serialize_derive")?;
@@ -133,8 +328,11 @@ impl Generator for RustGenerator {
 
         let abi_json = serde_json::to_string(event)?;
 
+        let derives = self.current_contract().derives.clone();
+
         self.current_contract().add_event_token_stream(quote! {
-            #[derive(#serialize_derive_macro,#deserialize_derive_macro)]
+            #doc_attrs
+            #[derive(#serialize_derive_macro,#deserialize_derive_macro #(,#derives)*)]
             pub struct #event_ident {
                 #(#event_field_list,)*
             }
@@ -145,7 +343,7 @@ impl Generator for RustGenerator {
                 }
             }
         });
-    
+
         Ok(())
     }
 
@@ -160,9 +358,14 @@ impl Generator for RustGenerator {
 
         let error_type = self.to_runtime_type_token_stream(runtime_binder, "rt_error")?;
 
-        let abi_decode = self.to_runtime_type_token_stream(runtime_binder, "rt_abi_deserialize")?;
-
-        let abi_encode = self.to_runtime_type_token_stream(runtime_binder, "rt_abi_serialize")?;
+        let (abi_encode, abi_decode) = if self.client_trait {
+            (quote!(C::abi_encode), quote!(C::abi_decode))
+        } else {
+            (
+                self.to_runtime_type_token_stream(runtime_binder, "rt_abi_serialize")?,
+                self.to_runtime_type_token_stream(runtime_binder, "rt_abi_deserialize")?,
+            )
+        };
 
         let receipt_type = self.to_runtime_type_token_stream(runtime_binder, "rt_receipt")?;
 
@@ -178,53 +381,110 @@ impl Generator for RustGenerator {
 
         let outputs_type = self.to_outputs_type(runtime_binder, &function.outputs)?;
 
-        let fn_ident = format_ident!("{}", function.name.to_snake_case());
-        let fn_with_ident = format_ident!("{}_with", function.name.to_snake_case());
+        let fn_name = function.name.to_snake_case();
 
-        let send_transaction = match function.state_mutability {
-            StateMutability::Pure | StateMutability::View => false,
-            _ => true,
-        };
+        let send_transaction = !matches!(
+            function.state_mutability,
+            StateMutability::Pure | StateMutability::View
+        );
 
         let fn_signature = function.signature();
 
-        if send_transaction {
-            self.current_contract().add_fn_token_stream(quote! {
-                pub async fn #fn_with_ident<Ops, #(#generic_list,)* >(&self, #(#param_list,)* ops: Ops) -> std::result::Result<#receipt_type,#error_type>
-                where Ops: TryInto<#opts_type>, Ops::Error: std::error::Error + Sync + Send + 'static, #(#where_clause_list,)*
-                {
-                    #(#try_into_list;)*
-                    let ops = ops.try_into()?;
+        let doc_attrs = to_doc_attrs(
+            &self.current_contract().natspec.function(function),
+            &function.inputs,
+            &function.outputs,
+        );
 
-                    let outputs = #abi_encode(&#abi_encode_list)?;
+        if self.call_builder {
+            let fn_ident = format_ident!("{}", fn_name);
 
-                    self.client.send_raw_transaction(#fn_signature, &self.address, outputs,ops).await
-                }
+            let outputs_type = if function.outputs.is_empty() {
+                quote!(())
+            } else {
+                outputs_type
+            };
 
-                pub async fn #fn_ident<#(#generic_list,)* >(&self, #(#param_list,)*) -> std::result::Result<#receipt_type,#error_type>
-                where #(#where_clause_list,)*
-                {
-                    #(#try_into_list;)*
-                  
-                    let outputs = #abi_encode(&#abi_encode_list)?;
+            let selector = to_selector(&fn_signature);
 
-                    self.client.send_raw_transaction(#fn_signature, &self.address, outputs, Default::default()).await
-                }
-            });
-        } else {
             self.current_contract().add_fn_token_stream(quote! {
-                pub async fn #fn_ident<#(#generic_list,)* >(&self, #(#param_list,)*) -> std::result::Result<#outputs_type,#error_type>
+                #doc_attrs
+                pub fn #fn_ident<#(#generic_list,)* >(&self, #(#param_list,)*) -> std::result::Result<ContractCall<'_, C, #outputs_type>,#error_type>
                 where #(#where_clause_list,)*
                 {
                     #(#try_into_list;)*
 
-                    let outputs = #abi_encode(&#abi_encode_list)?;
-
-                    let inputs = self.client.eth_call(#fn_signature, &self.address, outputs).await?;
+                    let inputs = #abi_encode(&#abi_encode_list)?;
 
-                    Ok(#abi_decode(inputs)?)
+                    Ok(ContractCall::new(&self.client, &self.address, #fn_signature, [#(#selector),*], inputs))
                 }
             });
+
+            return Ok(());
+        }
+
+        for flavor in self.call_mode.flavors() {
+            let Flavor {
+                asyncness,
+                awaiting,
+                ..
+            } = &flavor;
+
+            let fn_ident = flavor.ident(&fn_name);
+
+            if send_transaction {
+                let fn_with_ident = flavor.ident(&format!("{}_with", fn_name));
+
+                let send_raw_transaction = flavor.ident("send_raw_transaction");
+
+                let doc_with_attrs = to_doc_lines(&format!(
+                    "Send `{}` transaction with custom options, see [`{}`](Self::{}).",
+                    fn_signature, fn_ident, fn_ident
+                ));
+
+                self.current_contract().add_fn_token_stream(quote! {
+                    #doc_with_attrs
+                    pub #asyncness fn #fn_with_ident<Ops, #(#generic_list,)* >(&self, #(#param_list,)* ops: Ops) -> std::result::Result<#receipt_type,#error_type>
+                    where Ops: TryInto<#opts_type>, Ops::Error: std::error::Error + Sync + Send + 'static, #(#where_clause_list,)*
+                    {
+                        #(#try_into_list;)*
+                        let ops = ops.try_into()?;
+
+                        let outputs = #abi_encode(&#abi_encode_list)?;
+
+                        self.client.#send_raw_transaction(#fn_signature, &self.address, outputs,ops)#awaiting
+                    }
+
+                    #doc_attrs
+                    pub #asyncness fn #fn_ident<#(#generic_list,)* >(&self, #(#param_list,)*) -> std::result::Result<#receipt_type,#error_type>
+                    where #(#where_clause_list,)*
+                    {
+                        #(#try_into_list;)*
+
+                        let outputs = #abi_encode(&#abi_encode_list)?;
+
+                        self.client.#send_raw_transaction(#fn_signature, &self.address, outputs, Default::default())#awaiting
+                    }
+                });
+            } else {
+                let eth_call = flavor.ident("eth_call");
+
+                self.current_contract().add_fn_token_stream(quote! {
+                    #doc_attrs
+                    #[allow(clippy::needless_question_mark)]
+                    pub #asyncness fn #fn_ident<#(#generic_list,)* >(&self, #(#param_list,)*) -> std::result::Result<#outputs_type,#error_type>
+                    where #(#where_clause_list,)*
+                    {
+                        #(#try_into_list;)*
+
+                        let outputs = #abi_encode(&#abi_encode_list)?;
+
+                        let inputs = self.client.#eth_call(#fn_signature, &self.address, outputs)#awaiting?;
+
+                        Ok(#abi_decode(inputs)?)
+                    }
+                });
+            }
         }
 
         Ok(())
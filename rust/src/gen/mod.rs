@@ -1,11 +1,133 @@
+use ethbind_json::{Doc, Parameter};
+use heck::ToSnakeCase;
 use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Generated rust files layout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ModuleLayout {
+    /// One `.rs` file per contract plus shared `types.rs` and a `mod.rs` which declares and re-exports them,
+    /// the output directory can be used as rust module directly.
+    #[default]
+    Modules,
+    /// Same files as [`Modules`](ModuleLayout::Modules) without `mod.rs` and `use` statements,
+    /// the generated codes can be concatenated in place, e.g: by proc-macro.
+    Inline,
+}
+
+/// Generated contract methods flavor
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CallMode {
+    /// `async fn` methods calling async `rt_client` methods
+    #[default]
+    Async,
+    /// Blocking `fn` methods calling blocking `rt_client` methods of the same names
+    Blocking,
+    /// Both flavors side by side, the blocking methods and the blocking `rt_client` methods
+    /// are suffixed by `_blocking`, e.g: `balance_of_blocking` calls `eth_call_blocking`.
+    Both,
+}
+
+/// Method flavor generated for [`CallMode`]
+pub(crate) struct Flavor {
+    /// `async` keyword or nothing
+    pub(crate) asyncness: TokenStream,
+    /// `.await` or nothing
+    pub(crate) awaiting: TokenStream,
+    suffix: &'static str,
+}
+
+impl Flavor {
+    /// Returns `name` with flavor suffix, e.g: `balance_of_blocking`
+    pub(crate) fn ident(&self, name: &str) -> proc_macro2::Ident {
+        format_ident!("{}{}", name, self.suffix)
+    }
+
+    /// Returns the return type of `ContractClient` trait method with `output`,
+    /// async methods return `impl Future<Output = output> + Send`, so generic bindings can be spawned on multi-threaded runtimes.
+    pub(crate) fn trait_returns(&self, output: TokenStream) -> TokenStream {
+        if self.asyncness.is_empty() {
+            output
+        } else {
+            quote!(impl std::future::Future<Output = #output> + Send)
+        }
+    }
+}
+
+impl CallMode {
+    pub(crate) fn flavors(&self) -> Vec<Flavor> {
+        let async_flavor = || Flavor {
+            asyncness: quote!(async),
+            awaiting: quote!(.await),
+            suffix: "",
+        };
+
+        let blocking_flavor = |suffix| Flavor {
+            asyncness: quote!(),
+            awaiting: quote!(),
+            suffix,
+        };
+
+        match self {
+            Self::Async => vec![async_flavor()],
+            Self::Blocking => vec![blocking_flavor("")],
+            Self::Both => vec![async_flavor(), blocking_flavor("_blocking")],
+        }
+    }
+}
+
 /// The rust language generator for `Ethbind`
 #[derive(Debug, Default)]
 pub struct RustGenerator {
+    layout: ModuleLayout,
     contracts: Vec<ContractGenerator>,
+    /// Structs declared by all contracts, see [`StructGenerator`]
+    structs: Vec<StructGenerator>,
+    /// Generate `ContractClient` trait, see [`client_trait`](RustGenerator::client_trait)
+    client_trait: bool,
+    /// Generate call builders, see [`call_builder`](RustGenerator::call_builder)
+    call_builder: bool,
+    call_mode: CallMode,
 }
 
 impl RustGenerator {
+    /// Create new generator with providing output [`layout`](ModuleLayout)
+    pub fn new(layout: ModuleLayout) -> Self {
+        Self {
+            layout,
+            ..Default::default()
+        }
+    }
+
+    /// Generate a `ContractClient` trait in the shared `client.rs` file, which declares the runtime methods
+    /// required by bindings, and make contracts generic over it instead of the `rt_client` runtime type.
+    ///
+    /// The abi encoding is provided by the trait too, so `rt_client`, `rt_abi_serialize` and `rt_abi_deserialize`
+    /// keys are not used, the `rt_serialize_derive`/`rt_deserialize_derive` paths are also used as serde-like trait bounds.
+    pub fn client_trait(mut self) -> Self {
+        self.client_trait = true;
+        self
+    }
+
+    /// Make contract functions return a `ContractCall` builder instead of sending immediately, which holds
+    /// the target address, selector, encoded parameters and `rt_opts` options, and is executed by `.call()`, `.send()` or `.estimate_gas()`.
+    ///
+    /// The builder is generated in `client.rs`, so this option implies [`client_trait`](RustGenerator::client_trait).
+    /// The `ContractClient` trait requires extra `estimate_gas` method returning the `uint256` runtime type
+    /// and `encode_calldata` method building the calldata of `ContractCall::calldata`.
+    /// The transferred value is a field of the `rt_opts` type, which is set by the call options instead of a dedicated setter.
+    pub fn call_builder(mut self) -> Self {
+        self.client_trait = true;
+        self.call_builder = true;
+        self
+    }
+
+    /// Set generated contract methods flavor, [`CallMode::Async`] by default.
+    pub fn call_mode(mut self, call_mode: CallMode) -> Self {
+        self.call_mode = call_mode;
+        self
+    }
+
     /// Push new contract generator to back end of generation list
     pub(crate) fn new_contract(&mut self, name: &str) {
         self.contracts.push(ContractGenerator::new(name))
@@ -21,21 +143,89 @@ impl RustGenerator {
         runtime_binder: &mut R,
         name: &str,
     ) -> anyhow::Result<TokenStream> {
-        Ok(runtime_binder
-            .get(name)?
-            .parse()
-            .map_err(|e| anyhow::format_err!("{}", e))?)
+        parse_runtime_type(runtime_binder.get(name)?)
     }
 }
 
+/// Parse mapped runtime type into token stream, returns [`BindError::InvalidRuntimeType`](ethbind_gen::BindError::InvalidRuntimeType) on failure.
+pub(crate) fn parse_runtime_type(runtime_type: &str) -> anyhow::Result<TokenStream> {
+    runtime_type.parse().map_err(|err| {
+        ethbind_gen::BindError::InvalidRuntimeType(format!("`{}`, {}", runtime_type, err)).into()
+    })
+}
+
+/// Convert `NatSpec` documentation to `#[doc]` attributes, which are rendered as `///` comments.
+///
+/// `@param`/`@return` tags are listed under `# Parameters`/`# Returns` sections in `inputs`/`outputs` order,
+/// named by the generated rust variable names.
+pub(crate) fn to_doc_attrs(doc: &Doc, inputs: &[Parameter], outputs: &[Parameter]) -> TokenStream {
+    let mut sections = vec![];
+
+    if let Some(title) = &doc.title {
+        sections.push(format!("# {}", title));
+    }
+
+    if let Some(notice) = &doc.notice {
+        sections.push(notice.clone());
+    }
+
+    if let Some(details) = &doc.details {
+        sections.push(details.clone());
+    }
+
+    let params = inputs
+        .iter()
+        .filter_map(|param| {
+            doc.params
+                .get(&param.name)
+                .map(|text| format!("* `{}` - {}", param.name.to_snake_case(), text))
+        })
+        .collect::<Vec<_>>();
+
+    if !params.is_empty() {
+        sections.push(format!("# Parameters\n\n{}", params.join("\n")));
+    }
+
+    let returns = outputs
+        .iter()
+        .enumerate()
+        .filter_map(|(index, param)| {
+            doc.return_doc(&param.name, index).map(|text| {
+                if param.name.is_empty() {
+                    format!("* {}", text)
+                } else {
+                    format!("* `{}` - {}", param.name.to_snake_case(), text)
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if !returns.is_empty() {
+        sections.push(format!("# Returns\n\n{}", returns.join("\n")));
+    }
+
+    to_doc_lines(&sections.join("\n\n"))
+}
+
+/// Convert multi-line text to `#[doc]` attributes, returns empty stream if `text` is empty.
+pub(crate) fn to_doc_lines(text: &str) -> TokenStream {
+    let lines = text
+        .lines()
+        .map(|line| format!(" {}", line.trim()).trim_end().to_owned());
+
+    quote!(#(#[doc = #lines])*)
+}
+
 mod generator;
-pub use generator::*;
 
 mod function;
-pub use function::*;
 
 mod contract;
 use contract::*;
 
 mod event;
-pub use event::*;
+
+mod types;
+use types::*;
+
+mod client;
@@ -0,0 +1,232 @@
+use std::collections::BTreeSet;
+
+use anyhow::Context;
+use ethbind_json::{Parameter, SimpleType, Type};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::RustGenerator;
+
+use super::{parse_runtime_type, to_doc_lines};
+
+/// Rust struct declared for solidity user-defined struct, e.g: `struct IMaker.Metadata`
+#[derive(Debug)]
+pub(crate) struct StructGenerator {
+    /// Generated rust struct name
+    pub(crate) name: String,
+    /// Solidity qualified struct name, e.g: `IMaker.Metadata`
+    qualified_name: String,
+    /// Canonical tuple type, e.g: `(address,uint256)`
+    tuple: String,
+    pub(crate) token_stream: TokenStream,
+    /// Indexes of contracts which are using this struct
+    pub(crate) users: BTreeSet<usize>,
+}
+
+impl StructGenerator {
+    /// Struct used by more than one contract is declared in shared `types.rs`
+    pub(crate) fn is_shared(&self) -> bool {
+        self.users.len() > 1
+    }
+}
+
+/// Returns solidity qualified struct name of `internalType`, e.g: `struct IMaker.Metadata[]` => `IMaker.Metadata`
+fn to_qualified_name(internal_type: &str) -> Option<&str> {
+    let name = internal_type.strip_prefix("struct ")?;
+
+    Some(name.split('[').next().unwrap_or(name))
+}
+
+#[allow(clippy::wrong_self_convention)]
+impl RustGenerator {
+    /// Map abi parameter to rust type, tuple parameters are mapped to generated structs
+    /// if the `internalType` field is present, otherwise to rust tuples.
+    ///
+    /// Errors are reported with the parameter name and type.
+    pub(crate) fn to_rust_type<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        runtime_binder: &mut R,
+        param: &Parameter,
+    ) -> anyhow::Result<TokenStream> {
+        self.map_rust_type(runtime_binder, param).with_context(|| {
+            if param.name.is_empty() {
+                format!("Map unnamed parameter ({})", param.r#type)
+            } else {
+                format!("Map parameter `{}` ({})", param.name, param.r#type)
+            }
+        })
+    }
+
+    fn map_rust_type<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        runtime_binder: &mut R,
+        param: &Parameter,
+    ) -> anyhow::Result<TokenStream> {
+        if let Some(runtime_type) = runtime_binder.to_runtime_type(&param.r#type)? {
+            return parse_runtime_type(runtime_type);
+        }
+
+        let components = param.components.as_ref().ok_or(anyhow::format_err!(
+            "Tuple parameter {}'s components field is None",
+            param.name
+        ))?;
+
+        let mut field_types = vec![];
+
+        // Always walk through components, so nested structs are marked as used by current contract.
+        for c in components {
+            field_types.push(self.to_rust_type(runtime_binder, c)?);
+        }
+
+        let element = match param.internal_type.as_deref().and_then(to_qualified_name) {
+            Some(qualified_name) => {
+                let ident = self.declare_struct(runtime_binder, param, qualified_name)?;
+
+                quote!(#ident)
+            }
+            None => quote!((#(#field_types,)*)),
+        };
+
+        self.wrap_tuple_type(runtime_binder, &param.r#type, element)
+    }
+
+    /// Map parameter list to rust type list.
+    pub(crate) fn to_rust_types<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        runtime_binder: &mut R,
+        params: &[Parameter],
+    ) -> anyhow::Result<Vec<TokenStream>> {
+        let mut types = vec![];
+
+        for param in params {
+            types.push(self.to_rust_type(runtime_binder, param)?);
+        }
+
+        Ok(types)
+    }
+
+    /// Wrap tuple element type with array declarations of `r#type`, e.g: `tuple[][2]` => `[Vec<Struct>;2]`
+    fn wrap_tuple_type<R: ethbind_gen::RuntimeBinder>(
+        &self,
+        runtime_binder: &mut R,
+        r#type: &Type,
+        element: TokenStream,
+    ) -> anyhow::Result<TokenStream> {
+        match r#type {
+            Type::Simple(SimpleType::Tuple) | Type::Tuple(_) => Ok(element),
+            Type::Array(array) => {
+                let element = self.wrap_tuple_type(runtime_binder, &array.element, element)?;
+
+                parse_runtime_type(
+                    &runtime_binder
+                        .get("array")?
+                        .replace("$el", &element.to_string()),
+                )
+            }
+            Type::ArrayM(array_m) => {
+                let element = self.wrap_tuple_type(runtime_binder, &array_m.element, element)?;
+
+                parse_runtime_type(
+                    &runtime_binder
+                        .get("array_m")?
+                        .replace("$el", &element.to_string())
+                        .replace("$m", &array_m.m.to_string()),
+                )
+            }
+            _ => Err(anyhow::format_err!(
+                "Expect tuple or array of tuple, got {}",
+                r#type
+            )),
+        }
+    }
+
+    /// Declare rust struct for solidity struct parameter and returns the struct ident.
+    ///
+    /// Structs are deduplicated by qualified name and tuple type across all contracts,
+    /// if two different structs have the same name, the later one is prefixed with its parent name, e.g: `IMakerMetadata`.
+    fn declare_struct<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        runtime_binder: &mut R,
+        param: &Parameter,
+        qualified_name: &str,
+    ) -> anyhow::Result<proc_macro2::Ident> {
+        let tuple = param.canonical_type();
+
+        // Strip array suffix, e.g: `(address,uint256)[]` => `(address,uint256)`
+        let tuple = tuple[..=tuple.rfind(')').unwrap_or(tuple.len() - 1)].to_owned();
+
+        let contract_index = self.contracts.len() - 1;
+
+        if let Some(declared) = self
+            .structs
+            .iter_mut()
+            .find(|c| c.qualified_name == qualified_name && c.tuple == tuple)
+        {
+            declared.users.insert(contract_index);
+
+            return Ok(format_ident!("{}", declared.name));
+        }
+
+        let mut names = qualified_name.rsplit('.');
+
+        let mut name = names.next().unwrap_or(qualified_name).to_upper_camel_case();
+
+        if self.structs.iter().any(|c| c.name == name) {
+            name = qualified_name.replace('.', "_").to_upper_camel_case();
+        }
+
+        if self.structs.iter().any(|c| c.name == name) {
+            return Err(anyhow::format_err!(
+                "Struct {} conflicts with declared struct {}",
+                qualified_name,
+                name
+            ));
+        }
+
+        let components = param.components.as_deref().unwrap_or_default();
+
+        let field_types = self.to_rust_types(runtime_binder, components)?;
+
+        let fields = components
+            .iter()
+            .zip(field_types)
+            .enumerate()
+            .map(|(index, (c, field_type))| {
+                let field_ident = if !c.name.is_empty() {
+                    format_ident!("{}", c.name.to_snake_case())
+                } else {
+                    format_ident!("p{}", index)
+                };
+
+                quote!(pub #field_ident: #field_type)
+            })
+            .collect::<Vec<_>>();
+
+        let serialize_derive_macro =
+            self.to_runtime_type_token_stream(runtime_binder, "rt_serialize_derive")?;
+
+        let deserialize_derive_macro =
+            self.to_runtime_type_token_stream(runtime_binder, "rt_deserialize_derive")?;
+
+        let ident = format_ident!("{}", name);
+
+        let doc_attrs = to_doc_lines(&format!("Solidity struct `{}`", qualified_name));
+
+        self.structs.push(StructGenerator {
+            name,
+            qualified_name: qualified_name.to_owned(),
+            tuple,
+            token_stream: quote! {
+                #doc_attrs
+                #[derive(#serialize_derive_macro,#deserialize_derive_macro)]
+                pub struct #ident {
+                    #(#fields,)*
+                }
+            },
+            users: BTreeSet::from([contract_index]),
+        });
+
+        Ok(ident)
+    }
+}
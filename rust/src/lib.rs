@@ -8,7 +8,9 @@ mod token_stream;
 pub use token_stream::*;
 
 mod error;
-pub use error::*;
+
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
 
 pub type BindingBuilder = ethbind_gen::BindingBuilder<
     ethbind_gen::Executor<RustGenerator, ethbind_gen::JsonRuntimeBinder>,
@@ -38,4 +40,384 @@ mod tests {
 
         // contracts.save_to("./");
     }
+
+    #[test]
+    fn test_natspec() {
+        _ = pretty_env_logger::try_init();
+
+        let runtime_binder: JsonRuntimeBinder = include_str!("../macros/tests/mapping.json")
+            .parse()
+            .expect("Parse mapping");
+
+        let mut contracts = BindingBuilder::new((RustGenerator::default(), runtime_binder))
+            .bind_foundry(include_str!("../macros/tests/foundry.json"))
+            .finalize()
+            .expect("Generate data");
+
+        contracts.pretty().expect("Pretty");
+
+        let data = &contracts[0].files[0].data;
+
+        assert!(
+            data.contains("/// # A minimal token\n///\n/// Token used to test natspec support\n")
+        );
+        assert!(data.contains("/// * `supply` - Initial supply minted to the deployer\n"));
+        assert!(data.contains("/// Transfers `value` tokens to `to`\n"));
+        assert!(data.contains("/// * `success` - Always true\n"));
+        assert!(data.contains("/// * The balance of `owner`\n"));
+        assert!(data.contains("/// Emitted when tokens are moved\n"));
+        assert!(data.contains("/// The transferred amount\n"));
+        assert!(data.contains("pub enum TokenError {"));
+        assert!(data.contains("/// The sender doesn't have enough tokens\n"));
+        assert!(data.contains(
+            "Self::InsufficientBalance { .. } => \"InsufficientBalance(uint256,uint256)\""
+        ));
+    }
+
+    #[test]
+    fn test_module_layout() {
+        _ = pretty_env_logger::try_init();
+
+        let runtime_binder: JsonRuntimeBinder = include_str!("../macros/tests/mapping.json")
+            .parse()
+            .expect("Parse mapping");
+
+        let mut contracts = BindingBuilder::new((RustGenerator::default(), runtime_binder))
+            .bind_hardhat(include_str!("../macros/tests/abi.json"))
+            .bind(
+                "Exchange",
+                r#"[
+                    {"type":"function","name":"makers","inputs":[],"outputs":[{"name":"","type":"tuple[]","internalType":"struct IMaker.Metadata[]","components":[
+                        {"name":"sku","type":"address","internalType":"address"},
+                        {"name":"skuQuantityOrId","type":"uint256","internalType":"uint256"},
+                        {"name":"paymentCurrency","type":"address","internalType":"address"},
+                        {"name":"priceQuantityOrId","type":"uint256","internalType":"uint256"},
+                        {"name":"skuType","type":"uint128","internalType":"uint128"},
+                        {"name":"paymentCurrencyType","type":"uint128","internalType":"uint128"}
+                    ]}],"stateMutability":"view"},
+                    {"type":"function","name":"order","inputs":[{"name":"order_","type":"tuple","internalType":"struct Exchange.Order","components":[
+                        {"name":"id","type":"uint256","internalType":"uint256"}
+                    ]}],"outputs":[],"stateMutability":"nonpayable"}
+                ]"#,
+            )
+            .finalize()
+            .expect("Generate data");
+
+        contracts.pretty().expect("Pretty");
+
+        assert_eq!(contracts.len(), 3);
+
+        let wallet = &contracts[0].files[0];
+
+        assert_eq!(wallet.name, "personal_wallet.rs");
+        assert!(wallet.data.starts_with("use super::types::*;\n"));
+
+        let exchange = &contracts[1].files[0];
+
+        assert_eq!(exchange.name, "exchange.rs");
+        assert!(exchange
+            .data
+            .contains("pub struct Order {\n    pub id: mock::Int<false, 256>,\n}"));
+        assert!(exchange
+            .data
+            .contains("std::result::Result<Vec<Metadata>, anyhow::Error>"));
+
+        let types = &contracts[2].files[0];
+
+        assert_eq!(types.name, "types.rs");
+        assert!(types
+            .data
+            .contains("/// Solidity struct `IMaker.Metadata`\n"));
+        assert!(types
+            .data
+            .contains("pub struct Metadata {\n    pub sku: mock::Address,\n"));
+        assert!(!types.data.contains("Order"));
+
+        let modules = &contracts[2].files[1];
+
+        assert_eq!(modules.name, "mod.rs");
+        assert_eq!(
+            modules.data,
+            "pub mod types;\npub use types::*;\npub mod personal_wallet;\npub use personal_wallet::*;\npub mod exchange;\npub use exchange::*;\n"
+        );
+    }
+
+    #[test]
+    fn test_from_config() {
+        _ = pretty_env_logger::try_init();
+
+        let config = Config::load("macros/tests/ethbind.toml").expect("Load config");
+
+        let contracts = BindingBuilder::from_config(RustGenerator::default(), &config)
+            .expect("Create builder")
+            .finalize()
+            .expect("Generate data");
+
+        let wallet = &contracts[0].files[0];
+
+        assert_eq!(wallet.name, "wallet.rs");
+        assert!(wallet.data.contains("pub struct Wallet"));
+        assert!(!wallet.data.contains("mint_maker"));
+
+        let token = &contracts[1].files[0];
+
+        assert!(token.data.contains("balance_of"));
+        assert!(!token.data.contains("fn transfer"));
+        assert!(token.data.contains(
+            "# [derive (mock :: Serialize , mock :: Deserialize , Debug)] pub struct TokenTransfer"
+        ));
+    }
+
+    #[test]
+    fn test_filter() {
+        let runtime_binder: JsonRuntimeBinder = include_str!("../macros/tests/mapping.json")
+            .parse()
+            .expect("Parse mapping");
+
+        let contracts = BindingBuilder::new((RustGenerator::default(), runtime_binder))
+            .bind_foundry(include_str!("../macros/tests/foundry.json"))
+            .filter(
+                AbiFilter::default()
+                    .functions(ItemFilter::default().allow("transfer"))
+                    .skip(Category::Deploy)
+                    .skip(Category::Errors),
+            )
+            .finalize()
+            .expect("Generate data");
+
+        let data = &contracts[0].files[0].data;
+
+        assert!(data.contains("fn transfer"));
+        assert!(!data.contains("fn balance_of"));
+        assert!(!data.contains("fn deploy"));
+        assert!(!data.contains("TokenError"));
+        assert!(data.contains("TokenTransfer"));
+
+        let runtime_binder: JsonRuntimeBinder = include_str!("../macros/tests/mapping.json")
+            .parse()
+            .expect("Parse mapping");
+
+        let result = BindingBuilder::new((RustGenerator::default(), runtime_binder))
+            .bind_foundry(include_str!("../macros/tests/foundry.json"))
+            .filter(AbiFilter::default().events(ItemFilter::default().allow("Approval")))
+            .finalize();
+
+        assert!(result.is_err(), "Unknown event");
+
+        let result = BindingBuilder::new((RustGenerator::default(), JsonRuntimeBinder::default()))
+            .filter(AbiFilter::default().skip(Category::Deploy))
+            .bind_foundry(include_str!("../macros/tests/foundry.json"))
+            .finalize();
+
+        match result {
+            Err(err) => assert_eq!(err.to_string(), "Call bind_* before filter"),
+            Ok(_) => panic!("Filter before bind"),
+        }
+    }
+
+    #[test]
+    fn test_fn_runtime_binder() {
+        let mapping: serde_json::Value =
+            serde_json::from_str(include_str!("../macros/tests/mapping.json"))
+                .expect("Parse mapping");
+
+        let mut runtime_binder = FnRuntimeBinder::default()
+            .simple(|c| match c {
+                SimpleType::Address => quote::quote!(mock::Address),
+                SimpleType::Bool => quote::quote!(bool),
+                SimpleType::String => quote::quote!(String),
+                _ => quote::quote!(Vec<u8>),
+            })
+            .integer_m(|c| {
+                if c.m <= 128 {
+                    format!("{}{}", if c.signed { "i" } else { "u" }, c.m)
+                } else {
+                    format!("mock::Int<{},{}>", c.signed, c.m)
+                }
+            })
+            .bytes_m(|m| format!("[u8;{}]", m))
+            .array(|el| format!("Vec<{}>", el))
+            .array_m(|el, m| format!("[{};{}]", el, m));
+
+        for (key, value) in mapping.as_object().unwrap() {
+            if key.starts_with("rt_") {
+                let value = value.as_str().unwrap().to_owned();
+                runtime_binder = runtime_binder.key(key, move || value.clone());
+            }
+        }
+
+        let contracts =
+            ethbind_gen::BindingBuilder::<Executor<RustGenerator, FnRuntimeBinder>>::new((
+                RustGenerator::default(),
+                runtime_binder,
+            ))
+            .bind_hardhat(include_str!("../macros/tests/abi.json"))
+            .finalize()
+            .expect("Generate data");
+
+        let data = &contracts[0].files[0].data;
+
+        assert!(data.contains(": u128"));
+        assert!(data.contains("mock :: Int < false , 256 >"));
+    }
+
+    #[test]
+    fn test_client_trait() {
+        let runtime_binder: JsonRuntimeBinder = include_str!("../macros/tests/mapping.json")
+            .parse()
+            .expect("Parse mapping");
+
+        let mut contracts =
+            BindingBuilder::new((RustGenerator::default().client_trait(), runtime_binder))
+                .bind_hardhat(include_str!("../macros/tests/abi.json"))
+                .finalize()
+                .expect("Generate data");
+
+        contracts.pretty().expect("Pretty");
+
+        let data = &contracts[0].files[0].data;
+
+        assert!(data.contains("use super::client::*;"));
+        assert!(data.contains("pub struct PersonalWallet<C> {"));
+        assert!(data.contains("impl<C: ContractClient> PersonalWallet<C> {"));
+        assert!(data.contains("C::abi_encode(&"));
+        assert!(!data.contains("mock::Client"));
+
+        let client = &contracts[1].files[0];
+
+        assert_eq!(client.name, "client.rs");
+        assert!(client.data.contains("pub trait ContractClient {"));
+
+        let modules = &contracts[1].files[1];
+
+        assert!(modules
+            .data
+            .starts_with("pub mod client;\npub use client::*;\n"));
+    }
+
+    #[test]
+    fn test_call_builder() {
+        let runtime_binder: JsonRuntimeBinder = include_str!("../macros/tests/mapping.json")
+            .parse()
+            .expect("Parse mapping");
+
+        let mut contracts = BindingBuilder::new((
+            RustGenerator::new(ModuleLayout::Inline).call_builder(),
+            runtime_binder,
+        ))
+        .bind_foundry(include_str!("../macros/tests/foundry.json"))
+        .finalize()
+        .expect("Generate data");
+
+        contracts.pretty().expect("Pretty");
+
+        let data = contracts[0].files[0].data.replace(char::is_whitespace, "");
+
+        assert!(data.contains("ContractCall<'_,C,mock::Int<false,256>>"));
+        assert!(data.contains("[112u8,160u8,130u8,49u8]"));
+        assert!(data.contains("pubfntransfer<"));
+        assert!(!data.contains("fntransfer_with<"));
+        assert!(!data.contains("asyncfnbalance_of<"));
+
+        let client = &contracts[1].files[0].data;
+
+        assert!(client.contains("pub struct ContractCall<'a, C: ContractClient, R> {"));
+        assert!(client.contains("async fn estimate_gas("));
+    }
+
+    #[test]
+    fn test_call_mode() {
+        let generate = |call_mode| {
+            let runtime_binder: JsonRuntimeBinder = include_str!("../macros/tests/mapping.json")
+                .parse()
+                .expect("Parse mapping");
+
+            let mut contracts = BindingBuilder::new((
+                RustGenerator::new(ModuleLayout::Inline).call_mode(call_mode),
+                runtime_binder,
+            ))
+            .bind_foundry(include_str!("../macros/tests/foundry.json"))
+            .finalize()
+            .expect("Generate data");
+
+            contracts.pretty().expect("Pretty");
+
+            contracts[0].files[0].data.clone()
+        };
+
+        let data = generate(CallMode::Blocking);
+
+        assert!(!data.contains("async"));
+        assert!(!data.contains(".await"));
+        assert!(data.contains("pub fn balance_of<"));
+        assert!(data.contains(".eth_call("));
+
+        let data = generate(CallMode::Both);
+
+        assert!(data.contains("pub async fn balance_of<"));
+        assert!(data.contains("pub fn balance_of_blocking<"));
+        assert!(data.contains(".eth_call_blocking("));
+        assert!(data.contains("pub fn deploy_with_blocking<"));
+    }
+
+    #[test]
+    fn test_overloaded_errors() {
+        let runtime_binder: JsonRuntimeBinder = include_str!("../macros/tests/mapping.json")
+            .parse()
+            .expect("Parse mapping");
+
+        let abi = r#"[
+            { "type": "error", "name": "Bad", "inputs": [{ "name": "value", "type": "uint256" }] },
+            { "type": "error", "name": "Bad", "inputs": [{ "name": "account", "type": "address" }] }
+        ]"#;
+
+        let mut contracts = BindingBuilder::new((RustGenerator::default(), runtime_binder))
+            .bind("Foo", abi)
+            .finalize()
+            .expect("Generate data");
+
+        contracts.pretty().expect("Pretty");
+
+        let data = &contracts[0].files[0].data;
+
+        assert!(data.contains("    Bad { value: mock::Int<false, 256> },\n"));
+        assert!(data.contains("    Bad0 { account: mock::Address },\n"));
+        assert!(data.contains("Self::Bad { .. } => \"Bad(uint256)\""));
+        assert!(data.contains("Self::Bad0 { .. } => \"Bad(address)\""));
+    }
+
+    #[test]
+    fn test_validate() {
+        let runtime_binder: JsonRuntimeBinder =
+            r#"{ "address": "Address", "array": "Vec<$el>", "uint_m": "U$m" }"#
+                .parse()
+                .expect("Parse mapping");
+
+        let abi = r#"[{
+            "type": "function",
+            "name": "register",
+            "inputs": [
+                { "name": "names", "type": "string[]" },
+                { "name": "callback", "type": "function" },
+                { "name": "rate", "type": "ufixed64x10" },
+                { "name": "owner", "type": "address" }
+            ],
+            "outputs": [{ "name": "", "type": "uint256" }],
+            "stateMutability": "nonpayable"
+        }]"#;
+
+        let err = BindingBuilder::new((RustGenerator::default(), runtime_binder))
+            .bind("Registry", abi)
+            .finalize()
+            .map(|_| ())
+            .expect_err("Missing keys");
+
+        assert_eq!(
+            err.to_string(),
+            "Runtime binder missing mapping keys: \
+             `function` required by function (Registry.register parameter `callback`); \
+             `string` required by string[] (Registry.register parameter `names`); \
+             `ufixed_m_n` required by ufixed64x10 (Registry.register parameter `rate`)"
+        );
+    }
 }
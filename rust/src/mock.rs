@@ -0,0 +1,645 @@
+//! In-memory mock client, which allows testing generated bindings and the codes using them without network.
+//!
+//! Bind contracts with the mapping keys `rt_client = "ethbind_rust::mock::MockClient<mock::Ops>"`,
+//! `rt_abi_serialize = "ethbind_rust::mock::abi_encode"` and `rt_abi_deserialize = "ethbind_rust::mock::abi_decode"`,
+//! or implement the generated `ContractClient` trait by [`impl_mock_client!`](crate::impl_mock_client).
+//!
+//! Values are encoded as json instead of abi encoding, stubs are declared with the same rust values passed to/returned by bindings.
+//!
+//! Functions and errors are referred by full signature or `0x` prefixed selector, the stub and query methods
+//! panic on a malformed selector, e.g: `0x1234`, the same as a failed assertion of the test using the mock.
+//!
+//! This module is only available with the `mock` feature.
+
+use std::{
+    fmt::Debug,
+    future::Future,
+    marker::PhantomData,
+    ops::Deref,
+    pin::pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+};
+
+use ethbind_json::{to_hex, to_selector};
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+pub use serde::{Deserialize, Serialize};
+
+/// Encoded data passed between generated bindings and [`MockClient`]
+pub type MockData = serde_json::Value;
+
+/// Mock `rt_abi_serialize` fn
+pub fn abi_encode<T: Serialize>(value: T) -> anyhow::Result<MockData> {
+    Ok(serde_json::to_value(value)?)
+}
+
+/// Mock `rt_abi_deserialize` fn
+pub fn abi_decode<T: DeserializeOwned>(data: MockData) -> anyhow::Result<T> {
+    Ok(serde_json::from_value(data)?)
+}
+
+/// Mock calldata of the function `selector` followed by the encoded `inputs`,
+/// e.g: `{ "selector": "0x70a08231", "inputs": 2 }`.
+pub fn encode_calldata(selector: [u8; 4], inputs: &MockData) -> MockData {
+    serde_json::json!({ "selector": to_hex(selector), "inputs": inputs })
+}
+
+/// Run `future` to completion on current thread, the mock client never waits, so no async runtime is required.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+
+        std::thread::yield_now();
+    }
+}
+
+/// Returns selector of function/error `item`, which is a full signature or `0x` prefixed selector.
+///
+/// Panics if the `0x` prefixed selector is not 4 bytes hex.
+fn selector_of(item: &str) -> [u8; 4] {
+    match item.strip_prefix("0x") {
+        Some(hex) => {
+            let bytes = (0..hex.len())
+                .step_by(2)
+                .map(|i| {
+                    hex.get(i..i + 2)
+                        .and_then(|c| u8::from_str_radix(c, 16).ok())
+                })
+                .collect::<Option<Vec<_>>>();
+
+            bytes.and_then(|c| c.try_into().ok()).unwrap_or_else(|| {
+                panic!("Invalid selector {}, expect 0x prefixed 4 bytes hex", item)
+            })
+        }
+        None => to_selector(item),
+    }
+}
+
+/// Mock client method which is called by generated bindings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallKind {
+    Deploy,
+    Call,
+    Transaction,
+    EstimateGas,
+}
+
+/// Call recorded by [`MockClient`]
+#[derive(Debug, Clone)]
+pub struct MockCall {
+    pub kind: CallKind,
+    /// Function or constructor signature, e.g: `transfer(address,uint256)`
+    pub signature: String,
+    pub selector: [u8; 4],
+    /// Contract address, `null` for deploy call
+    pub address: MockData,
+    /// Encoded parameters
+    pub inputs: MockData,
+}
+
+/// Simulated contract revert with custom error payload
+#[derive(Debug, Clone, Error)]
+#[error("Reverted with {signature}")]
+pub struct MockRevert {
+    /// Custom error signature, e.g: `InsufficientBalance(uint256,uint256)`
+    pub signature: String,
+    pub selector: [u8; 4],
+    pub payload: MockData,
+}
+
+impl MockRevert {
+    /// Decode revert payload, e.g: into the generated `{Contract}Error` enum.
+    pub fn decode<T: DeserializeOwned>(&self) -> anyhow::Result<T> {
+        abi_decode(self.payload.clone())
+    }
+}
+
+/// Error returned by [`MockClient`]
+#[derive(Debug, Error)]
+pub enum MockError {
+    #[error(transparent)]
+    Revert(MockRevert),
+    /// Read-only call without stubbed return data
+    #[error("No stub for {0}")]
+    Unstubbed(String),
+    #[error(transparent)]
+    Data(#[from] serde_json::Error),
+}
+
+#[derive(Debug)]
+enum Outcome {
+    Return(MockData),
+    Revert(MockRevert),
+}
+
+#[derive(Debug)]
+struct Stub {
+    selector: [u8; 4],
+    /// Matches any arguments if `None`
+    inputs: Option<MockData>,
+    outcome: Outcome,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    stubs: Vec<Stub>,
+    calls: Vec<MockCall>,
+}
+
+/// In-memory client which records calls by selector and returns stubbed data,
+/// `O` is the `rt_opts` runtime type, e.g: `rt_client = "ethbind_rust::mock::MockClient<mock::Ops>"`.
+///
+/// Clones share the same state, so tests can keep a handle of the client moved into contracts.
+pub struct MockClient<O = ()> {
+    state: Arc<Mutex<MockState>>,
+    _ops: PhantomData<fn(O)>,
+}
+
+impl<O> Default for MockClient<O> {
+    fn default() -> Self {
+        Self {
+            state: Default::default(),
+            _ops: PhantomData,
+        }
+    }
+}
+
+impl<O> Clone for MockClient<O> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            _ops: PhantomData,
+        }
+    }
+}
+
+impl<O> Debug for MockClient<O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockClient")
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl<O> MockClient<O> {
+    /// Create new client without stubs.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().expect("Lock mock state")
+    }
+
+    fn add_stub(&self, function: &str, inputs: Option<MockData>, outcome: Outcome) -> &Self {
+        self.state().stubs.push(Stub {
+            selector: selector_of(function),
+            inputs,
+            outcome,
+        });
+
+        self
+    }
+
+    fn to_data<T: Serialize>(value: T) -> MockData {
+        serde_json::to_value(value).expect("Serialize mock data")
+    }
+
+    fn to_revert<E: Serialize>(error: &str, payload: E) -> Outcome {
+        Outcome::Revert(MockRevert {
+            signature: error.to_owned(),
+            selector: selector_of(error),
+            payload: Self::to_data(payload),
+        })
+    }
+
+    /// Stub `returns` of `function` called with any arguments, `function` is a full signature or selector.
+    ///
+    /// Deploy calls are stubbed by constructor signature, e.g: `Constructor(uint256)`, which returns the contract address.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `function` is a malformed selector, the same applies to the other `stub_*`/`revert_*` methods.
+    pub fn stub<R: Serialize>(&self, function: &str, returns: R) -> &Self {
+        self.add_stub(function, None, Outcome::Return(Self::to_data(returns)))
+    }
+
+    /// Stub `returns` of `function` called with `args`, a single parameter or the tuple of parameters.
+    ///
+    /// Argument-specific stubs take precedence over [`stub`](MockClient::stub).
+    pub fn stub_with<A: Serialize, R: Serialize>(
+        &self,
+        function: &str,
+        args: A,
+        returns: R,
+    ) -> &Self {
+        self.add_stub(
+            function,
+            Some(Self::to_data(args)),
+            Outcome::Return(Self::to_data(returns)),
+        )
+    }
+
+    /// Revert `function` called with any arguments by custom `error` signature and `payload`.
+    pub fn revert<E: Serialize>(&self, function: &str, error: &str, payload: E) -> &Self {
+        self.add_stub(function, None, Self::to_revert(error, payload))
+    }
+
+    /// Revert `function` called with `args` by custom `error` signature and `payload`.
+    pub fn revert_with<A: Serialize, E: Serialize>(
+        &self,
+        function: &str,
+        args: A,
+        error: &str,
+        payload: E,
+    ) -> &Self {
+        self.add_stub(
+            function,
+            Some(Self::to_data(args)),
+            Self::to_revert(error, payload),
+        )
+    }
+
+    /// Returns all recorded calls in calling order.
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.state().calls.clone()
+    }
+
+    /// Returns recorded calls of `function`, a full signature or selector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `function` is a malformed selector.
+    pub fn calls_to(&self, function: &str) -> Vec<MockCall> {
+        let selector = selector_of(function);
+
+        self.state()
+            .calls
+            .iter()
+            .filter(|c| c.selector == selector)
+            .cloned()
+            .collect()
+    }
+
+    /// Remove recorded calls, stubs are kept.
+    pub fn clear_calls(&self) {
+        self.state().calls.clear();
+    }
+
+    /// Record call and returns the matching stub outcome, the latest stub wins.
+    fn invoke(
+        &self,
+        kind: CallKind,
+        signature: &str,
+        address: MockData,
+        inputs: MockData,
+    ) -> Result<Option<MockData>, MockError> {
+        let selector = to_selector(signature);
+
+        let mut state = self.state();
+
+        state.calls.push(MockCall {
+            kind,
+            signature: signature.to_owned(),
+            selector,
+            address,
+            inputs: inputs.clone(),
+        });
+
+        let stubs = state.stubs.iter().rev().filter(|c| c.selector == selector);
+
+        let stub = stubs
+            .clone()
+            .find(|c| c.inputs.as_ref() == Some(&inputs))
+            .or_else(|| stubs.clone().find(|c| c.inputs.is_none()));
+
+        match stub.map(|c| &c.outcome) {
+            Some(Outcome::Return(data)) => Ok(Some(data.clone())),
+            Some(Outcome::Revert(revert)) => Err(MockError::Revert(revert.clone())),
+            None => Ok(None),
+        }
+    }
+
+    /// Deploy contract, returns the stubbed address or `A::default()`.
+    pub async fn deploy_contract<A>(
+        &self,
+        signature: &str,
+        inputs: MockData,
+        bytecode: &str,
+        ops: O,
+    ) -> Result<A, MockError>
+    where
+        A: DeserializeOwned + Default,
+    {
+        self.deploy_contract_blocking(signature, inputs, bytecode, ops)
+    }
+
+    /// Call read-only function, returns [`MockError::Unstubbed`] if the function is not stubbed.
+    pub async fn eth_call<A: Serialize>(
+        &self,
+        signature: &str,
+        address: &A,
+        inputs: MockData,
+    ) -> Result<MockData, MockError> {
+        self.eth_call_blocking(signature, address, inputs)
+    }
+
+    /// Send transaction, returns the stubbed receipt or `R::default()`.
+    pub async fn send_raw_transaction<A, R, E>(
+        &self,
+        signature: &str,
+        address: &A,
+        inputs: MockData,
+        ops: O,
+    ) -> Result<R, E>
+    where
+        A: Serialize,
+        R: DeserializeOwned + Default,
+        E: From<MockError>,
+    {
+        self.send_raw_transaction_blocking(signature, address, inputs, ops)
+    }
+
+    /// Estimate transaction gas, returns `G::default()` or the stubbed revert error, return stubs are ignored.
+    pub async fn estimate_gas<A, G>(
+        &self,
+        signature: &str,
+        address: &A,
+        inputs: &MockData,
+        ops: &O,
+    ) -> Result<G, MockError>
+    where
+        A: Serialize,
+        G: Default,
+    {
+        self.estimate_gas_blocking(signature, address, inputs, ops)
+    }
+
+    /// Blocking version of [`deploy_contract`](MockClient::deploy_contract)
+    pub fn deploy_contract_blocking<A>(
+        &self,
+        signature: &str,
+        inputs: MockData,
+        _bytecode: &str,
+        _ops: O,
+    ) -> Result<A, MockError>
+    where
+        A: DeserializeOwned + Default,
+    {
+        match self.invoke(CallKind::Deploy, signature, MockData::Null, inputs)? {
+            Some(address) => Ok(serde_json::from_value(address)?),
+            None => Ok(A::default()),
+        }
+    }
+
+    /// Blocking version of [`eth_call`](MockClient::eth_call)
+    pub fn eth_call_blocking<A: Serialize>(
+        &self,
+        signature: &str,
+        address: &A,
+        inputs: MockData,
+    ) -> Result<MockData, MockError> {
+        self.invoke(CallKind::Call, signature, Self::to_data(address), inputs)?
+            .ok_or_else(|| MockError::Unstubbed(signature.to_owned()))
+    }
+
+    /// Blocking version of [`send_raw_transaction`](MockClient::send_raw_transaction)
+    pub fn send_raw_transaction_blocking<A, R, E>(
+        &self,
+        signature: &str,
+        address: &A,
+        inputs: MockData,
+        _ops: O,
+    ) -> Result<R, E>
+    where
+        A: Serialize,
+        R: DeserializeOwned + Default,
+        E: From<MockError>,
+    {
+        let receipt = self.invoke(
+            CallKind::Transaction,
+            signature,
+            Self::to_data(address),
+            inputs,
+        )?;
+
+        match receipt {
+            Some(receipt) => Ok(serde_json::from_value(receipt).map_err(MockError::from)?),
+            None => Ok(R::default()),
+        }
+    }
+
+    /// Blocking version of [`estimate_gas`](MockClient::estimate_gas)
+    pub fn estimate_gas_blocking<A, G>(
+        &self,
+        signature: &str,
+        address: &A,
+        inputs: &MockData,
+        _ops: &O,
+    ) -> Result<G, MockError>
+    where
+        A: Serialize,
+        G: Default,
+    {
+        self.invoke(
+            CallKind::EstimateGas,
+            signature,
+            Self::to_data(address),
+            inputs.clone(),
+        )?;
+
+        Ok(G::default())
+    }
+}
+
+/// [`MockClient`] with blocking client methods of the async names, used by bindings generated
+/// in [`CallMode::Blocking`](crate::CallMode::Blocking) mode, e.g: `rt_client = "ethbind_rust::mock::BlockingMockClient<mock::Ops>"`.
+pub struct BlockingMockClient<O = ()>(pub MockClient<O>);
+
+impl<O> Default for BlockingMockClient<O> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<O> Clone for BlockingMockClient<O> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<O> Debug for BlockingMockClient<O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("BlockingMockClient").field(&self.0).finish()
+    }
+}
+
+impl<O> Deref for BlockingMockClient<O> {
+    type Target = MockClient<O>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<O> BlockingMockClient<O> {
+    /// Create new client without stubs.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// See [`MockClient::deploy_contract`]
+    pub fn deploy_contract<A>(
+        &self,
+        signature: &str,
+        inputs: MockData,
+        bytecode: &str,
+        ops: O,
+    ) -> Result<A, MockError>
+    where
+        A: DeserializeOwned + Default,
+    {
+        self.0
+            .deploy_contract_blocking(signature, inputs, bytecode, ops)
+    }
+
+    /// See [`MockClient::eth_call`]
+    pub fn eth_call<A: Serialize>(
+        &self,
+        signature: &str,
+        address: &A,
+        inputs: MockData,
+    ) -> Result<MockData, MockError> {
+        self.0.eth_call_blocking(signature, address, inputs)
+    }
+
+    /// See [`MockClient::send_raw_transaction`]
+    pub fn send_raw_transaction<A, R, E>(
+        &self,
+        signature: &str,
+        address: &A,
+        inputs: MockData,
+        ops: O,
+    ) -> Result<R, E>
+    where
+        A: Serialize,
+        R: DeserializeOwned + Default,
+        E: From<MockError>,
+    {
+        self.0
+            .send_raw_transaction_blocking(signature, address, inputs, ops)
+    }
+
+    /// See [`MockClient::estimate_gas`]
+    pub fn estimate_gas<A, G>(
+        &self,
+        signature: &str,
+        address: &A,
+        inputs: &MockData,
+        ops: &O,
+    ) -> Result<G, MockError>
+    where
+        A: Serialize,
+        G: Default,
+    {
+        self.0
+            .estimate_gas_blocking(signature, address, inputs, ops)
+    }
+}
+
+/// Implement the generated `ContractClient` trait for [`MockClient`](crate::mock::MockClient),
+/// the types are the `address`, `rt_opts`, `rt_receipt` and `rt_error` runtime types of the mapping, e.g:
+///
+/// ```ignore
+/// ethbind_rust::impl_mock_client!(ContractClient, address = mock::Address, ops = mock::Ops, receipt = mock::Receipt, error = anyhow::Error);
+/// ```
+///
+/// The `rt_serialize_derive`/`rt_deserialize_derive` keys must be serde's `Serialize`/`Deserialize`.
+///
+/// Bindings generated with call builders also require the `estimate_gas` and `encode_calldata` methods, which are implemented
+/// by appending the `uint256` runtime type, e.g: `gas = mock::Int<false, 256>`.
+#[macro_export]
+macro_rules! impl_mock_client {
+    ($client:path, address = $address:ty, ops = $ops:ty, receipt = $receipt:ty, error = $error:ty $(, gas = $gas:ty)?) => {
+        impl $client for $crate::mock::MockClient<$ops> {
+            type Data = $crate::mock::MockData;
+
+            fn abi_encode<T: $crate::mock::Serialize>(
+                value: &T,
+            ) -> std::result::Result<Self::Data, $error> {
+                Ok($crate::mock::abi_encode(value)?)
+            }
+
+            fn abi_decode<T: for<'de> $crate::mock::Deserialize<'de>>(
+                data: Self::Data,
+            ) -> std::result::Result<T, $error> {
+                Ok($crate::mock::abi_decode(data)?)
+            }
+
+            async fn deploy_contract(
+                &self,
+                signature: &str,
+                inputs: Self::Data,
+                bytecode: &str,
+                ops: $ops,
+            ) -> std::result::Result<$address, $error> {
+                Ok($crate::mock::MockClient::deploy_contract(
+                    self, signature, inputs, bytecode, ops,
+                )
+                .await?)
+            }
+
+            async fn eth_call(
+                &self,
+                signature: &str,
+                address: &$address,
+                inputs: Self::Data,
+            ) -> std::result::Result<Self::Data, $error> {
+                Ok($crate::mock::MockClient::eth_call(self, signature, address, inputs).await?)
+            }
+
+            async fn send_raw_transaction(
+                &self,
+                signature: &str,
+                address: &$address,
+                inputs: Self::Data,
+                ops: $ops,
+            ) -> std::result::Result<$receipt, $error> {
+                $crate::mock::MockClient::send_raw_transaction(
+                    self, signature, address, inputs, ops,
+                )
+                .await
+            }
+
+            $(
+                fn encode_calldata(
+                    selector: [u8; 4],
+                    inputs: &Self::Data,
+                ) -> std::result::Result<Self::Data, $error> {
+                    Ok($crate::mock::encode_calldata(selector, inputs))
+                }
+
+                async fn estimate_gas(
+                    &self,
+                    signature: &str,
+                    address: &$address,
+                    inputs: &Self::Data,
+                    ops: &$ops,
+                ) -> std::result::Result<$gas, $error> {
+                    Ok(
+                        $crate::mock::MockClient::estimate_gas(self, signature, address, inputs, ops)
+                            .await?,
+                    )
+                }
+            )?
+        }
+    };
+}
@@ -1,7 +1,11 @@
-use std::{env, fs, path::PathBuf, process::Command};
+use std::{
+    env,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Stdio},
+};
 
 use ethbind_gen::Contract;
-use sha3::{Digest, Keccak256};
 
 /// The trait to support `Rust` language formatting
 pub trait RustPretty {
@@ -11,31 +15,38 @@ pub trait RustPretty {
 
 impl RustPretty for Contract {
     fn pretty(&mut self) -> anyhow::Result<()> {
-        let rust_fmt_path =
-            PathBuf::from(env::var("CARGO_HOME").expect("Get CARGO_HOME")).join("bin/rustfmt");
+        // Search `rustfmt` in `PATH` if not running by cargo, e.g: via `ethbind` cli
+        let rust_fmt_path = env::var("CARGO_HOME")
+            .map(|c| PathBuf::from(c).join("bin/rustfmt"))
+            .unwrap_or_else(|_| PathBuf::from("rustfmt"));
 
         for file in &mut self.files {
-            let temp_file_name = format!(
-                "{:x}",
-                Keccak256::new()
-                    .chain_update(file.data.as_bytes())
-                    .finalize()
-            );
-
-            let path = env::temp_dir().join(temp_file_name);
-
-            fs::write(&path, file.data.as_bytes())?;
-
-            // Call rustfmt to fmt tmp file
+            // Format codes via stdin, so rustfmt doesn't try to resolve `mod` declarations of `mod.rs`.
+            // `#[doc]` attributes are rendered as `///` comments
             let mut child = Command::new(&rust_fmt_path)
-                .args(["--edition", "2021", path.to_str().unwrap()])
+                .args([
+                    "--edition",
+                    "2021",
+                    "--config",
+                    "normalize_doc_attributes=true",
+                ])
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
                 .spawn()?;
 
-            child.wait()?;
+            child
+                .stdin
+                .take()
+                .expect("Open rustfmt stdin")
+                .write_all(file.data.as_bytes())?;
+
+            let output = child.wait_with_output()?;
 
-            let formated = fs::read_to_string(path).expect("Read formated codes");
+            if !output.status.success() {
+                return Err(anyhow::format_err!("rustfmt {} failed", file.name));
+            }
 
-            file.data = formated;
+            file.data = String::from_utf8(output.stdout)?;
         }
 
         Ok(())
@@ -0,0 +1,82 @@
+//! Compile generated call builders against the mock client.
+
+mod common;
+
+use ethbind_rust::*;
+
+use common::*;
+
+#[test]
+fn test_call_builder() {
+    let source = format!(
+        "{}{}{}",
+        MOCK,
+        generate(
+            RustGenerator::new(ModuleLayout::Inline).call_builder(),
+            "ethbind_rust::mock::MockClient<mock::Ops>"
+        ),
+        r#"
+ethbind_rust::impl_mock_client!(
+    ContractClient,
+    address = mock::Address,
+    ops = mock::Ops,
+    receipt = mock::TransactionReceipt,
+    error = anyhow::Error,
+    gas = mock::Int<false, 256>
+);
+
+fn assert_send<T: Send>(_: T) {}
+
+/// Compiles only if the trait declares `Send` futures.
+fn spawnable<C: ContractClient>(client: &C, address: &mock::Address, inputs: C::Data) {
+    assert_send(client.eth_call("balanceOf(address)", address, inputs));
+}
+
+fn main() {
+    use ethbind_rust::mock::{block_on, CallKind, MockClient};
+
+    let client = MockClient::<mock::Ops>::new();
+
+    client.stub("balanceOf(address)", mock::Int::<false, 256>(100));
+
+    let token = Token {
+        client: client.clone(),
+        address: mock::Address(1),
+    };
+
+    let call = token.balance_of(mock::Address(2)).unwrap();
+
+    assert_eq!(call.target(), &mock::Address(1));
+    assert_eq!(call.signature(), "balanceOf(address)");
+    assert_eq!(call.selector(), [0x70, 0xa0, 0x82, 0x31]);
+    assert_eq!(call.inputs(), &serde_json::json!(2));
+    assert_eq!(
+        call.calldata().unwrap(),
+        serde_json::json!({ "selector": "0x70a08231", "inputs": 2 })
+    );
+    assert!(client.calls().is_empty());
+
+    assert_eq!(block_on(call.call()).unwrap(), mock::Int(100));
+
+    let transfer = token
+        .transfer(mock::Address(2), mock::Int::<false, 256>(1))
+        .unwrap()
+        .ops(mock::Ops);
+
+    assert_eq!(block_on(transfer.estimate_gas()).unwrap(), mock::Int(0));
+
+    block_on(transfer.send()).unwrap();
+
+    let kinds = client.calls().iter().map(|c| c.kind).collect::<Vec<_>>();
+
+    assert_eq!(kinds, [CallKind::Call, CallKind::EstimateGas, CallKind::Transaction]);
+
+    spawnable(&client, &mock::Address(1), serde_json::json!(2));
+}
+"#
+    );
+
+    let t = trybuild::TestCases::new();
+
+    t.pass(write_test("call_builder", "mock", &source));
+}
@@ -0,0 +1,67 @@
+//! Compile generated bindings of every [`CallMode`] against the mock client.
+
+mod common;
+
+use ethbind_rust::*;
+
+use common::*;
+
+#[test]
+fn test_call_mode() {
+    let blocking = format!(
+        "{}{}{}",
+        MOCK,
+        generate(
+            RustGenerator::new(ModuleLayout::Inline).call_mode(CallMode::Blocking),
+            "ethbind_rust::mock::BlockingMockClient<mock::Ops>"
+        ),
+        r#"
+fn main() {
+    let client = ethbind_rust::mock::BlockingMockClient::<mock::Ops>::new();
+
+    client.stub("balanceOf(address)", mock::Int::<false, 256>(100));
+
+    let token = Token::deploy_with(client.clone(), mock::Int::<false, 256>(1), mock::Ops).unwrap();
+
+    assert_eq!(token.balance_of(mock::Address(1)).unwrap(), mock::Int(100));
+
+    token.transfer(mock::Address(2), mock::Int::<false, 256>(1)).unwrap();
+
+    assert_eq!(client.calls().len(), 3);
+}
+"#
+    );
+
+    let both = format!(
+        "{}{}{}",
+        MOCK,
+        generate(
+            RustGenerator::new(ModuleLayout::Inline).call_mode(CallMode::Both),
+            "ethbind_rust::mock::MockClient<mock::Ops>"
+        ),
+        r#"
+fn main() {
+    use ethbind_rust::mock::block_on;
+
+    let client = ethbind_rust::mock::MockClient::<mock::Ops>::new();
+
+    client.stub("balanceOf(address)", mock::Int::<false, 256>(100));
+
+    let token = Token::deploy_with_blocking(client.clone(), mock::Int::<false, 256>(1), mock::Ops).unwrap();
+
+    assert_eq!(token.balance_of_blocking(mock::Address(1)).unwrap(), mock::Int(100));
+    assert_eq!(block_on(token.balance_of(mock::Address(1))).unwrap(), mock::Int(100));
+
+    token.transfer_blocking(mock::Address(2), mock::Int::<false, 256>(1)).unwrap();
+    block_on(token.transfer(mock::Address(2), mock::Int::<false, 256>(1))).unwrap();
+
+    assert_eq!(client.calls().len(), 5);
+}
+"#
+    );
+
+    let t = trybuild::TestCases::new();
+
+    t.pass(write_test("call_mode", "blocking", &blocking));
+    t.pass(write_test("call_mode", "both", &both));
+}
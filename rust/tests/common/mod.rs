@@ -0,0 +1,63 @@
+//! Helpers which compile generated bindings against the mock client by `trybuild`.
+
+use std::{fs, path::PathBuf};
+
+use ethbind_rust::*;
+
+/// Mock runtime types of `macros/tests/mapping.json`
+pub const MOCK: &str = r#"
+#[allow(unused)]
+mod mock {
+    pub use serde::{Deserialize, Serialize};
+
+    #[derive(Default)]
+    pub struct Ops;
+
+    #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct Address(pub u8);
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct TransactionReceipt;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    pub struct Int<const SIGN: bool, const LEN: usize>(pub u64);
+}
+"#;
+
+/// Generate test contracts into one inline source, `rt_client` is replaced by `client`.
+pub fn generate(generator: RustGenerator, client: &str) -> String {
+    let mut runtime_binder: serde_json::Value =
+        serde_json::from_str(include_str!("../../macros/tests/mapping.json"))
+            .expect("Parse mapping");
+
+    runtime_binder["rt_client"] = client.into();
+    runtime_binder["rt_abi_serialize"] = "ethbind_rust::mock::abi_encode".into();
+    runtime_binder["rt_abi_deserialize"] = "ethbind_rust::mock::abi_decode".into();
+
+    let runtime_binder: JsonRuntimeBinder =
+        serde_json::from_value(runtime_binder).expect("Load mapping");
+
+    let contracts = BindingBuilder::new((generator, runtime_binder))
+        .bind_hardhat(include_str!("../../macros/tests/abi.json"))
+        .bind_foundry(include_str!("../../macros/tests/foundry.json"))
+        .finalize()
+        .expect("Generate data");
+
+    contracts
+        .iter()
+        .flat_map(|c| c.files.iter().map(|f| f.data.as_str()))
+        .collect()
+}
+
+/// Write test program `name.rs` to the target tmp dir, returns the file path.
+pub fn write_test(dir: &str, name: &str, source: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(dir);
+
+    fs::create_dir_all(&dir).expect("Create test dir");
+
+    let path = dir.join(format!("{}.rs", name));
+
+    fs::write(&path, source).expect("Write test program");
+
+    path
+}
@@ -0,0 +1,150 @@
+use ethbind_gen::Generator;
+use ethbind_json::*;
+
+use crate::SolidityGenerator;
+
+use super::types::Location;
+
+/// Returns state mutability modifier, `nonpayable` functions have no modifier.
+fn to_modifier(state_mutability: &StateMutability) -> &'static str {
+    match state_mutability {
+        StateMutability::Pure => " pure",
+        StateMutability::View => " view",
+        StateMutability::Nonpayable => "",
+        StateMutability::Payable => " payable",
+    }
+}
+
+impl Generator for SolidityGenerator {
+    fn begin<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        name: &str,
+    ) -> anyhow::Result<()> {
+        self.new_interface(name);
+
+        Ok(())
+    }
+
+    fn uses_runtime_types(&self) -> bool {
+        false
+    }
+
+    fn end<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        _name: &str,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn finalize<R: ethbind_gen::RuntimeBinder>(
+        self,
+        _runtime_binder: &mut R,
+    ) -> anyhow::Result<Vec<ethbind_gen::Contract>> {
+        let mut contracts = vec![];
+
+        for c in &self.interfaces {
+            contracts.push(c.finalize(&self.license, &self.pragma)?);
+        }
+
+        Ok(contracts)
+    }
+
+    fn generate_deploy<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        _contructor: &Constructor,
+        _deploy_bytes: &str,
+    ) -> anyhow::Result<()> {
+        // Interfaces can't declare constructor
+        Ok(())
+    }
+
+    fn generate_error<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        error: &Error,
+    ) -> anyhow::Result<()> {
+        log::trace!("generate error {}", error.name);
+
+        let inputs = self.to_declaration_list(&error.inputs, None, false)?;
+
+        self.current_interface()
+            .add_error(format!("    error {}({});", error.name, inputs));
+
+        Ok(())
+    }
+
+    fn generate_event<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        event: &Event,
+    ) -> anyhow::Result<()> {
+        log::trace!("generate event {}", event.name);
+
+        let inputs = self.to_declaration_list(&event.inputs, None, true)?;
+
+        let anonymous = if event.anonymous { " anonymous" } else { "" };
+
+        self.current_interface().add_event(format!(
+            "    event {}({}){};",
+            event.name, inputs, anonymous
+        ));
+
+        Ok(())
+    }
+
+    fn generate_fn<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        function: &Function,
+    ) -> anyhow::Result<()> {
+        log::trace!("generate fn {}", function.name);
+
+        let inputs = self.to_declaration_list(&function.inputs, Some(Location::Calldata), false)?;
+
+        let returns = if function.outputs.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " returns ({})",
+                self.to_declaration_list(&function.outputs, Some(Location::Memory), false)?
+            )
+        };
+
+        self.current_interface().add_fn(format!(
+            "    function {}({}) external{}{};",
+            function.name,
+            inputs,
+            to_modifier(&function.state_mutability),
+            returns
+        ));
+
+        Ok(())
+    }
+
+    fn generate_receive<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        _receive: &Receive,
+    ) -> anyhow::Result<()> {
+        self.current_interface()
+            .add_fn("    receive() external payable;".to_owned());
+
+        Ok(())
+    }
+
+    fn generate_fallback<R: ethbind_gen::RuntimeBinder>(
+        &mut self,
+        _runtime_binder: &mut R,
+        fallback: &Fallback,
+    ) -> anyhow::Result<()> {
+        self.current_interface().add_fn(format!(
+            "    fallback() external{};",
+            to_modifier(&fallback.state_mutability)
+        ));
+
+        Ok(())
+    }
+}
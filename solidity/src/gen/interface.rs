@@ -0,0 +1,96 @@
+use ethbind_gen::{Contract, File};
+
+use super::to_interface_name;
+
+#[derive(Debug, Default)]
+pub(crate) struct InterfaceGenerator {
+    pub(crate) contract_name: String,
+    /// Declared structs, (name, `internalType` struct path or anonymous tuple components, declaration)
+    structs: Vec<(String, String, String)>,
+    errors: Vec<String>,
+    events: Vec<String>,
+    fns: Vec<String>,
+}
+
+impl InterfaceGenerator {
+    pub(crate) fn new(contract_name: &str) -> Self {
+        Self {
+            contract_name: contract_name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns struct name declared for `key`
+    pub(crate) fn declared_struct(&self, key: &str) -> Option<&str> {
+        self.structs
+            .iter()
+            .find(|(_, k, _)| k == key)
+            .map(|(n, _, _)| n.as_str())
+    }
+
+    pub(crate) fn has_struct(&self, name: &str) -> bool {
+        self.structs.iter().any(|(n, _, _)| n == name)
+    }
+
+    pub(crate) fn structs_len(&self) -> usize {
+        self.structs.len()
+    }
+
+    /// Reserve struct `name` for `key` before generating its fields, so nested tuples can't take the same name.
+    /// Returns the index to [`set_struct_declaration`](Self::set_struct_declaration).
+    pub(crate) fn reserve_struct(&mut self, name: &str, key: &str) -> usize {
+        self.structs
+            .push((name.to_owned(), key.to_owned(), String::new()));
+
+        self.structs.len() - 1
+    }
+
+    pub(crate) fn set_struct_declaration(&mut self, index: usize, declaration: String) {
+        self.structs[index].2 = declaration;
+    }
+
+    pub(crate) fn add_error(&mut self, declaration: String) {
+        self.errors.push(declaration);
+    }
+
+    pub(crate) fn add_event(&mut self, declaration: String) {
+        self.events.push(declaration);
+    }
+
+    pub(crate) fn add_fn(&mut self, declaration: String) {
+        self.fns.push(declaration);
+    }
+
+    pub(crate) fn finalize(&self, license: &str, pragma: &str) -> anyhow::Result<Contract> {
+        let name = to_interface_name(&self.contract_name);
+
+        let sections = [
+            self.structs
+                .iter()
+                .map(|(_, _, declaration)| declaration.clone())
+                .collect::<Vec<_>>(),
+            self.errors.clone(),
+            self.events.clone(),
+            self.fns.clone(),
+        ];
+
+        let body = sections
+            .iter()
+            .filter(|section| !section.is_empty())
+            .map(|section| section.join("\n"))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let data = format!(
+            "// SPDX-License-Identifier: {}\npragma solidity {};\n\ninterface {} {{\n{}\n}}\n",
+            license, pragma, name, body
+        );
+
+        Ok(Contract {
+            files: vec![File {
+                name: format!("{}.sol", name),
+                data,
+            }],
+        })
+    }
+}
@@ -0,0 +1,89 @@
+use ethbind_gen::{Generatable, JsonRuntimeBinder};
+use ethbind_json::AbiField;
+use heck::ToUpperCamelCase;
+
+/// The solidity interface generator for `Ethbind`
+///
+/// This generator reconstructs solidity `interface` source from contract abi, so it doesn't need any runtime type mapping,
+/// the [`RuntimeBinder`](ethbind_gen::RuntimeBinder) parameters are ignored.
+#[derive(Debug)]
+pub struct SolidityGenerator {
+    license: String,
+    pragma: String,
+    interfaces: Vec<InterfaceGenerator>,
+}
+
+impl Default for SolidityGenerator {
+    fn default() -> Self {
+        Self {
+            license: "UNLICENSED".to_owned(),
+            // custom errors require solidity 0.8.4
+            pragma: ">=0.8.4 <0.9.0".to_owned(),
+            interfaces: Default::default(),
+        }
+    }
+}
+
+impl SolidityGenerator {
+    /// Set the `SPDX-License-Identifier` of generated files, default is `UNLICENSED`
+    pub fn license<L: AsRef<str>>(mut self, license: L) -> Self {
+        self.license = license.as_ref().to_owned();
+        self
+    }
+
+    /// Set the `pragma solidity` version requirement of generated files, default is `>=0.8.4 <0.9.0`
+    pub fn pragma<P: AsRef<str>>(mut self, pragma: P) -> Self {
+        self.pragma = pragma.as_ref().to_owned();
+        self
+    }
+
+    /// Generate solidity interface source code for contract `name` with abi `fields`
+    pub fn interface<N: AsRef<str>>(name: N, fields: &[AbiField]) -> anyhow::Result<String> {
+        let mut executor: ethbind_gen::Executor<_, _> =
+            (SolidityGenerator::default(), JsonRuntimeBinder::default()).into();
+
+        {
+            let (generator, runtime_binder) = ethbind_gen::Context::get_mut(&mut executor);
+
+            ethbind_gen::Generator::begin(generator, runtime_binder, name.as_ref())?;
+        }
+
+        fields.generate(&mut executor)?;
+
+        let (generator, mut runtime_binder) = ethbind_gen::Context::finalize(executor);
+
+        let mut contracts = ethbind_gen::Generator::finalize(generator, &mut runtime_binder)?;
+
+        Ok(contracts.remove(0).files.remove(0).data)
+    }
+
+    /// Push new interface generator to back end of generation list
+    pub(crate) fn new_interface(&mut self, name: &str) {
+        self.interfaces.push(InterfaceGenerator::new(name))
+    }
+
+    /// Returns interface generator at back edn of generation list.
+    pub(crate) fn current_interface(&mut self) -> &mut InterfaceGenerator {
+        self.interfaces
+            .last_mut()
+            .expect("Call new_interface first")
+    }
+}
+
+/// Convert contract name to interface name, e.g: `Lock` => `ILock`, `IERC20` => `IERC20`
+pub(crate) fn to_interface_name(contract_name: &str) -> String {
+    let mut chars = contract_name.chars();
+
+    if chars.next() == Some('I') && chars.next().map(|c| c.is_uppercase()) == Some(true) {
+        contract_name.to_owned()
+    } else {
+        format!("I{}", contract_name.to_upper_camel_case())
+    }
+}
+
+mod generator;
+
+mod types;
+
+mod interface;
+use interface::*;
@@ -0,0 +1,262 @@
+use ethbind_json::{Parameter, Type};
+
+use crate::SolidityGenerator;
+
+/// Data location of function parameters with reference type.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Location {
+    Calldata,
+    Memory,
+}
+
+#[allow(clippy::wrong_self_convention)]
+impl SolidityGenerator {
+    /// Convert parameter to solidity declaration, e.g: `address indexed from`, `Order[] calldata orders`
+    pub(crate) fn to_declaration(
+        &mut self,
+        param: &Parameter,
+        location: Option<Location>,
+        indexed: bool,
+    ) -> anyhow::Result<String> {
+        let mut declaration = self.to_solidity_type(param)?;
+
+        if let Some(location) = location {
+            if !param.r#type.is_value_type() {
+                declaration.push_str(match location {
+                    Location::Calldata => " calldata",
+                    Location::Memory => " memory",
+                });
+            }
+        }
+
+        if indexed && param.indexed {
+            declaration.push_str(" indexed");
+        }
+
+        if !param.name.is_empty() {
+            declaration.push(' ');
+            declaration.push_str(&to_identifier(&param.name));
+        }
+
+        Ok(declaration)
+    }
+
+    /// Convert parameter list to comma separated solidity declaration list.
+    pub(crate) fn to_declaration_list(
+        &mut self,
+        params: &[Parameter],
+        location: Option<Location>,
+        indexed: bool,
+    ) -> anyhow::Result<String> {
+        let mut declarations = vec![];
+
+        for param in params {
+            declarations.push(self.to_declaration(param, location, indexed)?);
+        }
+
+        Ok(declarations.join(", "))
+    }
+
+    /// Convert parameter type to solidity type, tuple types are converted to declared struct names.
+    fn to_solidity_type(&mut self, param: &Parameter) -> anyhow::Result<String> {
+        // json abi form, e.g: `(address,uint256)[]` => `tuple[]`
+        let r#type = param.r#type.detach_components().to_string();
+
+        if param.components.is_some() {
+            let struct_name = self.declare_struct(param)?;
+
+            // keep array suffix of tuple type, e.g: `tuple[][2]` => `[][2]`
+            let suffix = r#type.strip_prefix("tuple").unwrap_or_default();
+
+            return Ok(format!("{}{}", struct_name, suffix));
+        }
+
+        // external function type, encoded as `function` in abi
+        if let Some(suffix) = r#type.strip_prefix("function") {
+            return Ok(format!("function() external{}", suffix));
+        }
+
+        Ok(r#type)
+    }
+
+    /// Declare solidity struct for tuple parameter and returns the struct name.
+    ///
+    /// Structs are keyed by `internalType` struct path, or by components of anonymous tuple.
+    fn declare_struct(&mut self, param: &Parameter) -> anyhow::Result<String> {
+        let components = param.components.as_ref().ok_or(anyhow::format_err!(
+            "Tuple parameter {}'s components field is None",
+            param.name
+        ))?;
+
+        let key = match param.struct_path() {
+            Some(path) => path.to_owned(),
+            None => serde_json::to_string(components)?,
+        };
+
+        let interface = self.current_interface();
+
+        if let Some(name) = interface.declared_struct(&key) {
+            return Ok(name.to_owned());
+        }
+
+        let mut struct_name = match param.struct_name() {
+            Some(name) => name.to_owned(),
+            None => format!("Tuple{}", interface.structs_len()),
+        };
+
+        // Different structs with the same name, e.g: `struct A.Order` and `struct B.Order`
+        if interface.has_struct(&struct_name) {
+            if let Some(path) = param.struct_path() {
+                struct_name = path.replace('.', "_");
+            }
+        }
+
+        let base_name = struct_name.clone();
+
+        let mut suffix = 0;
+
+        while interface.has_struct(&struct_name) {
+            struct_name = format!("{}_{}", base_name, suffix);
+            suffix += 1;
+        }
+
+        let index = interface.reserve_struct(&struct_name, &key);
+
+        let mut fields = vec![];
+
+        for (index, c) in components.iter().enumerate() {
+            let field_type = self.to_solidity_type(c)?;
+
+            if c.name.is_empty() {
+                fields.push(format!("        {} field{};", field_type, index));
+            } else {
+                fields.push(format!(
+                    "        {} {};",
+                    field_type,
+                    to_identifier(&c.name)
+                ));
+            }
+        }
+
+        self.current_interface().set_struct_declaration(
+            index,
+            format!(
+                "    struct {} {{\n{}\n    }}",
+                struct_name,
+                fields.join("\n")
+            ),
+        );
+
+        Ok(struct_name)
+    }
+}
+
+/// Solidity keywords and reserved keywords which are not elementary type names,
+/// `from`, `error`, `revert` and `global` are allowed identifiers.
+const KEYWORDS: &[&str] = &[
+    "abstract",
+    "after",
+    "alias",
+    "anonymous",
+    "apply",
+    "as",
+    "assembly",
+    "auto",
+    "break",
+    "byte",
+    "calldata",
+    "case",
+    "catch",
+    "constant",
+    "constructor",
+    "continue",
+    "contract",
+    "copyof",
+    "days",
+    "default",
+    "define",
+    "delete",
+    "do",
+    "else",
+    "emit",
+    "enum",
+    "ether",
+    "event",
+    "external",
+    "fallback",
+    "false",
+    "final",
+    "for",
+    "gwei",
+    "hours",
+    "if",
+    "immutable",
+    "implements",
+    "import",
+    "in",
+    "indexed",
+    "inline",
+    "interface",
+    "internal",
+    "is",
+    "let",
+    "library",
+    "macro",
+    "mapping",
+    "match",
+    "memory",
+    "minutes",
+    "modifier",
+    "mutable",
+    "new",
+    "null",
+    "of",
+    "override",
+    "partial",
+    "payable",
+    "pragma",
+    "private",
+    "promise",
+    "public",
+    "pure",
+    "receive",
+    "reference",
+    "relocatable",
+    "return",
+    "returns",
+    "sealed",
+    "seconds",
+    "sizeof",
+    "static",
+    "storage",
+    "struct",
+    "super",
+    "supports",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typedef",
+    "typeof",
+    "unchecked",
+    "unicode",
+    "using",
+    "var",
+    "view",
+    "virtual",
+    "weeks",
+    "wei",
+    "while",
+    "years",
+];
+
+/// Escape parameter or field name which is a solidity keyword by appending `_`, e.g: `type` => `type_`
+fn to_identifier(name: &str) -> String {
+    if KEYWORDS.contains(&name) || name.parse::<Type>().is_ok() {
+        format!("{}_", name)
+    } else {
+        name.to_owned()
+    }
+}
@@ -0,0 +1,166 @@
+mod gen;
+pub use gen::*;
+
+pub type BindingBuilder = ethbind_gen::BindingBuilder<
+    ethbind_gen::Executor<SolidityGenerator, ethbind_gen::JsonRuntimeBinder>,
+>;
+
+pub use ethbind_gen::*;
+pub use ethbind_json::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gen() {
+        _ = pretty_env_logger::try_init();
+
+        let contracts =
+            BindingBuilder::new((SolidityGenerator::default(), JsonRuntimeBinder::default()))
+                .bind_hardhat(include_str!("../../rust/macros/tests/abi.json"))
+                .finalize()
+                .expect("Generate data");
+
+        let file = &contracts[0].files[0];
+
+        assert_eq!(file.name, "IPersonalWallet.sol");
+
+        assert!(file.data.starts_with(
+            "// SPDX-License-Identifier: UNLICENSED\npragma solidity >=0.8.4 <0.9.0;\n\ninterface IPersonalWallet {\n"
+        ));
+
+        assert!(file
+            .data
+            .contains("    struct Metadata {\n        address sku;\n"));
+
+        assert!(file.data.contains(
+            "    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);"
+        ));
+
+        assert!(file.data.contains(
+            "    function makerMetadata(uint256 makerId_) external view returns (Metadata memory maker_, uint256 sentSkuQuantityOrId_, uint256 receivedPaymentQuantityOrId_, address dex_);"
+        ));
+
+        assert!(file.data.contains(
+            "    function mintMaker(Metadata calldata maker_, address dex_) external payable returns (uint256 makerId_);"
+        ));
+
+        // struct declared once
+        assert_eq!(file.data.matches("struct Metadata").count(), 1);
+    }
+
+    #[test]
+    fn test_interface() {
+        let fields: Vec<AbiField> = serde_json::from_str(
+            r#"[
+                {"type":"error","name":"InsufficientBalance","inputs":[{"name":"available","type":"uint256"},{"name":"required","type":"uint256"}]},
+                {"type":"event","name":"Log","anonymous":true,"inputs":[{"name":"data","type":"bytes","indexed":false}]},
+                {"type":"function","name":"name","inputs":[],"outputs":[{"name":"","type":"string"}],"stateMutability":"pure"},
+                {"type":"function","name":"batch","inputs":[
+                    {"name":"orders","type":"tuple[]","internalType":"struct Lib.Order[]","components":[
+                        {"name":"maker","type":"address","internalType":"address"},
+                        {"name":"amounts","type":"uint256[10]","internalType":"uint256[10]"}
+                    ]}
+                ],"outputs":[],"stateMutability":"nonpayable"},
+                {"type":"receive","stateMutability":"payable"},
+                {"type":"fallback","stateMutability":"nonpayable"}
+            ]"#,
+        )
+        .expect("Parse abi");
+
+        let source = SolidityGenerator::interface("Exchange", &fields).expect("Generate interface");
+
+        assert_eq!(
+            source,
+            r#"// SPDX-License-Identifier: UNLICENSED
+pragma solidity >=0.8.4 <0.9.0;
+
+interface IExchange {
+    struct Order {
+        address maker;
+        uint256[10] amounts;
+    }
+
+    error InsufficientBalance(uint256 available, uint256 required);
+
+    event Log(bytes data) anonymous;
+
+    function name() external pure returns (string memory);
+    function batch(Order[] calldata orders) external;
+    receive() external payable;
+    fallback() external;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_nested_tuple_structs() {
+        let fields: Vec<AbiField> = serde_json::from_str(
+            r#"[
+                {"type":"function","name":"first","inputs":[
+                    {"name":"value","type":"tuple","components":[
+                        {"name":"id","type":"uint256"},
+                        {"name":"inner","type":"tuple[]","components":[
+                            {"name":"owner","type":"address"},
+                            {"name":"type","type":"uint8"}
+                        ]}
+                    ]}
+                ],"outputs":[],"stateMutability":"nonpayable"},
+                {"type":"function","name":"second","inputs":[
+                    {"name":"value","type":"tuple","components":[
+                        {"name":"id","type":"uint256"},
+                        {"name":"inner","type":"tuple[]","components":[
+                            {"name":"owner","type":"address"},
+                            {"name":"type","type":"uint8"}
+                        ]}
+                    ]},
+                    {"name":"orders","type":"tuple[]","internalType":"struct B.Order[]","components":[
+                        {"name":"taker","type":"address"}
+                    ]},
+                    {"name":"order","type":"tuple","internalType":"struct A.Order","components":[
+                        {"name":"maker","type":"address"}
+                    ]}
+                ],"outputs":[],"stateMutability":"nonpayable"},
+                {"type":"event","name":"Typed","anonymous":false,"inputs":[
+                    {"name":"from","type":"address","indexed":true},
+                    {"name":"type","type":"uint256","indexed":false},
+                    {"name":"address","type":"address","indexed":false}
+                ]}
+            ]"#,
+        )
+        .expect("Parse abi");
+
+        let source = SolidityGenerator::interface("Nested", &fields).expect("Generate interface");
+
+        assert_eq!(
+            source,
+            r#"// SPDX-License-Identifier: UNLICENSED
+pragma solidity >=0.8.4 <0.9.0;
+
+interface INested {
+    struct Tuple0 {
+        uint256 id;
+        Tuple1[] inner;
+    }
+    struct Tuple1 {
+        address owner;
+        uint8 type_;
+    }
+    struct Order {
+        address taker;
+    }
+    struct A_Order {
+        address maker;
+    }
+
+    event Typed(address indexed from, uint256 type_, address address_);
+
+    function first(Tuple0 calldata value) external;
+    function second(Tuple0 calldata value, Order[] calldata orders, A_Order calldata order) external;
+}
+"#
+        );
+    }
+}
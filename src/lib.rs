@@ -1,6 +1,12 @@
 pub use ethbind_gen as gen;
+pub use ethbind_go as go;
 pub use ethbind_json as json;
+pub use ethbind_markdown as markdown;
 pub use ethbind_rust as rust;
+pub use ethbind_solidity as solidity;
 
 #[cfg(feature = "macros")]
 pub use ethbind_rust_macros as rust_macros;
+
+#[cfg(feature = "macros")]
+pub use ethbind_rust_macros::{bind, contract};